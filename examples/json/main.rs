@@ -2,7 +2,7 @@
 
 use std::fs;
 
-use parseal::{parsing::{self, Group, List, tokens::{Bracket, Comma, Brace, Colon}, Number, StringValue, Parse, charstream::CharStream, Identifier}, Parsable};
+use parseal::{parsing::{self, convert::{self, FromAst, ConvertError}, incremental::{Incremental, Feed}, Group, List, tokens::{Bracket, Comma, Brace, Colon}, Number, StringValue, Parse, charstream::CharStream, Identifier, Entry, Key}, Parsable};
 
 #[derive(Debug, Parsable, Clone)]
 pub struct JSONList {
@@ -12,17 +12,36 @@ pub struct JSONList {
 
 #[derive(Debug, Parsable, Clone)]
 pub struct NamedValue {
+	#[commit]
 	name: StringValue,
 	colon: Colon,
 	value: JSONNode
 }
 
+impl Entry for NamedValue {
+	type Value = JSONNode;
+
+	fn key(&self) -> &str {
+		self.name.key()
+	}
+
+	fn value(&self) -> &JSONNode {
+		&self.value
+	}
+}
+
 #[derive(Debug, Parsable, Clone)]
 pub struct JSONObject {
 	map: Group<Brace,
 		List<NamedValue, Comma>>
 }
 
+impl JSONObject {
+	pub fn get(&self, name: &str) -> Option<&JSONNode> {
+		self.map.item().get(name)
+	}
+}
+
 #[derive(Debug, Parsable, Clone)]
 pub enum Value {
 	String(StringValue),
@@ -37,6 +56,22 @@ pub enum JSONNode {
 	Value(Value)
 }
 
+#[derive(Debug)]
+pub enum Scalar {
+	Str(String),
+	Num(f64)
+}
+
+impl FromAst<Value> for Scalar {
+	fn from_ast(value: Value) -> Result<Self, ConvertError> {
+		match value {
+			Value::String(value) => Ok(Self::Str(String::from_ast(value)?)),
+			Value::Number(value) => Ok(Self::Num(f64::from_ast(value)?)),
+			Value::Bool(value) => Err(ConvertError::new("Booleans can not be converted to a scalar.", value.span().start))
+		}
+	}
+}
+
 fn main() {
 	let file = fs::read_to_string("examples/json/example.json")
 		.expect("Expected example file to exist.");
@@ -44,4 +79,34 @@ fn main() {
 	let mut buffer = CharStream::new(file).build();
 	let value = JSONNode::parse(&mut buffer);
 	println!("value: {:#?}", value);
+
+	if let Ok(JSONNode::Object(object)) = &value {
+		println!("name: {:#?}", object.get("name"));
+	}
+
+	let scalar = convert::parse_into::<Value, Scalar>("\"Hello, World!\"".to_owned());
+	println!("scalar: {:?}", scalar);
+
+	#[cfg(feature = "trace")]
+	{
+		use parsing::charstream::Trace;
+
+		let mut buffer = CharStream::new("{\"values\": [1, true]}".to_owned())
+			.trace(|trace| match trace {
+				Trace::Enter { name, position, depth } => println!("{}> {} at {}", "  ".repeat(*depth), name, position),
+				Trace::Exit { name, position, depth, error: None } => println!("{}< {} at {}", "  ".repeat(*depth), name, position),
+				Trace::Exit { name, position, depth, error: Some(error) } => println!("{}< {} failed: {:?}", "  ".repeat(*depth), name, error)
+			})
+			.build();
+		let _ = JSONNode::parse(&mut buffer);
+	}
+
+	let mut parser = Incremental::<JSONNode>::new();
+	for chunk in ["{\"name\": \"par", "seal\", \"version\": 2}\n"] {
+		match parser.feed(chunk) {
+			Ok(Feed::NeedMore) => println!("need more input after: {:?}", chunk),
+			Ok(Feed::Done(value)) => println!("streamed value: {:#?}", value),
+			Err(error) => println!("error: {:?}", error)
+		}
+	}
 }
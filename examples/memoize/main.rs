@@ -0,0 +1,33 @@
+use std::{rc::Rc, time::Instant};
+
+use parseal::{parsing::{self, charstream::CharStream, tokens::{Hyphen, Paren, Plus}, Group, Number, Parse}, Parsable};
+
+#[derive(Debug, Parsable, Clone)]
+pub enum Term {
+	Group(Group<Paren, Rc<Expr>>),
+	Number(Number)
+}
+
+// every variant starts with a `Term`, so without memoization every term is parsed three times on every level.
+#[derive(Debug, Parsable, Clone)]
+pub enum Expr {
+	Add(Term, Plus, Rc<Expr>),
+	Sub(Term, Hyphen, Rc<Expr>),
+	Term(Term)
+}
+
+fn main() {
+	let depth = 11;
+	let input = format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+
+	let start = Instant::now();
+	let plain = Expr::parse(&mut CharStream::new(input.clone()).build());
+	println!("without memoization: {:?}", start.elapsed());
+
+	let start = Instant::now();
+	let memoized = Expr::parse(&mut CharStream::new(input).memoize().build());
+	println!("with memoization: {:?}", start.elapsed());
+
+	assert_eq!(format!("{:?}", plain), format!("{:?}", memoized));
+	println!("both results are the same: {:?}", memoized.map(|expr| expr.variant_name()));
+}
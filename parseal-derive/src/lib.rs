@@ -3,16 +3,58 @@ use quote::{quote, ToTokens};
 use syn::{DeriveInput, parse_macro_input, Data, Error, spanned::Spanned, DataStruct, DataEnum, Ident, Fields, Field, Attribute, Meta, MetaList, Index};
 
 
-#[proc_macro_derive(Parsable, attributes(whitespace, value))]
+#[proc_macro_derive(Parsable, attributes(whitespace, value, commit, tag, variant))]
 pub fn parsable_fn(item: TokenStream) -> TokenStream {
     let item = parse_macro_input!(item as DeriveInput);
     match &item.data {
         Data::Struct(value) => derive_struct(&item.ident, value),
-        Data::Enum(value) => derive_enum(&item.ident, value),
+        Data::Enum(value) => derive_enum(&item.ident, value, &item.attrs),
         Data::Union(_) => TokenStream::from(Error::new(item.span(), "Can not derive Parse from a union type.").to_compile_error())
     }
 }
 
+#[proc_macro_derive(ParsableKeyword, attributes(value))]
+pub fn parsable_keyword_fn(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    match &item.data {
+        Data::Enum(value) => derive_keywords(&item.ident, value),
+        _ => TokenStream::from(Error::new(item.span(), "Can only derive ParsableKeyword for an enum.").to_compile_error())
+    }
+}
+
+fn derive_keywords(ident: &Ident, value: &DataEnum) -> TokenStream {
+    let mut keywords = Vec::new();
+    let mut arms = Vec::new();
+    for variant in &value.variants {
+        let variant_ident = &variant.ident;
+        if !matches!(variant.fields, Fields::Unit) {
+            return TokenStream::from(Error::new(variant.span(), "Every variant of a keyword enum has to be a unit variant.").to_compile_error());
+        }
+
+        let keyword = match get_attr(&variant.attrs, "value").and_then(|attr| attr.nested.into_iter().next()) {
+            Some(keyword) => keyword.into_token_stream(),
+            None => variant_ident.to_string().into_token_stream()
+        };
+        keywords.push(keyword.clone());
+        arms.push(quote! {
+            #keyword => ::std::option::Option::Some(Self::#variant_ident)
+        });
+    }
+
+    quote! {
+        impl parsing::Keywords for #ident {
+            const KEYWORDS: &'static [&'static str] = &[#(#keywords),*];
+
+            fn from_keyword(keyword: &str) -> ::std::option::Option<Self> {
+                match keyword {
+                    #(#arms,)*
+                    _ => ::std::option::Option::None
+                }
+            }
+        }
+    }.into()
+}
+
 fn derive_struct(ident: &Ident, value: &DataStruct) -> TokenStream {
     let fields = value.fields.iter().collect::<Vec<_>>();
     let definitions = derive_fields(fields.clone());
@@ -34,29 +76,37 @@ fn derive_struct(ident: &Ident, value: &DataStruct) -> TokenStream {
         }
         Fields::Unit => return TokenStream::from(Error::new(ident.span(), "Can not derive trait Parse for a unit struct.").to_compile_error())
     };
-    let first_ident = get_ident(&fields.first().unwrap().ident, 0);
-    let last_ident = get_ident(&fields.last().unwrap().ident, fields.len() - 1);
+    if fields.is_empty() {
+        return TokenStream::from(Error::new(ident.span(), "Can not derive trait Parse for a struct without fields, it needs a field for its span.").to_compile_error());
+    }
+    let span_body = span_body(fields.iter().enumerate().map(|(i, field)| {
+        let ident = get_ident(&field.ident, i);
+        quote! { self.#ident }
+    }).collect());
+    let parse_body = trace_body(ident, memoize_body(frame_body(quote! { stringify!(#ident) }, quote! {
+        #(#definitions)*
+        #parse_result
+    })));
     quote! {
         impl Parse for #ident {
             fn parse(value: &mut parsing::charstream::CharStream) -> ::std::result::Result<Self, parsing::ParseError> {
-                #(#definitions)*
-                #parse_result
+                #parse_body
             }
 
             fn span(&self) -> parsing::charstream::Span {
-                parsing::charstream::Span::new(self.#first_ident.span().start, self.#last_ident.span().end)
+                #span_body
             }
         }
     }.into()
 }
 
-fn derive_enum(ident: &Ident, value: &DataEnum) -> TokenStream {
+fn derive_enum(ident: &Ident, value: &DataEnum, attrs: &Vec<Attribute>) -> TokenStream {
     let variants = value.variants.iter().map(|variant| {
         let ident = Ident::new(&format!("__parse_{}", variant.ident.to_string().to_lowercase()), variant.span());
         (&variant.ident, ident, &variant.fields, &variant.attrs)
     });
     let variant_functions = match variants.clone()
-        .map(|(field_ident, func_ident, fields, attrs)| derive_variant_function(field_ident, func_ident, fields, attrs))
+        .map(|(field_ident, func_ident, fields, attrs)| derive_variant_function(ident, field_ident, func_ident, fields, attrs))
         .collect::<Result<Vec<_>,_>>() {
             Ok(value) => value,
             Err(error) => return error
@@ -64,15 +114,72 @@ fn derive_enum(ident: &Ident, value: &DataEnum) -> TokenStream {
 
     let parse_variants = variants.clone().map(|(_, func_ident, _, _)| {
         quote! {
-            let mut __value = value.clone();
-            match Self::#func_ident(&mut __value) {
-                ::std::result::Result::Ok(inner) => {
-                    value.goto(__value.position())?;
-                    options.push(inner);
+            if !committed {
+                let mut __value = value.clone();
+                match Self::#func_ident(&mut __value) {
+                    ::std::result::Result::Ok(inner) => {
+                        options.push((inner, __value.position()));
+                    }
+                    ::std::result::Result::Err(err) => {
+                        value.record_failure(&err);
+                        committed = err.is_committed();
+                        match &error {
+                            ::std::option::Option::Some(previous) if !committed && previous.position() > err.position() => {}
+                            _ => error = ::std::option::Option::Some(err)
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    let parse_body = match get_attr(attrs, "tag") {
+        Some(tag) => {
+            let tag_name = tag.nested;
+            let mut expected = Vec::new();
+            let mut arms = Vec::new();
+            for (variant_ident, func_ident, _, attrs) in variants.clone() {
+                let names = match get_attr(attrs, "variant") {
+                    Some(attr) => attr.nested.into_iter().collect::<Vec<_>>(),
+                    None => return TokenStream::from(Error::new(variant_ident.span(), "Every variant of an enum with a tag needs a `#[variant(...)]` attribute.").to_compile_error())
+                };
+                expected.extend(names.iter().map(|name| name.to_token_stream().to_string()));
+                arms.push(quote! {
+                    #(#names)|* => Self::#func_ident(value)
+                });
+            }
+            let expected = expected.join(", ");
+            quote! {
+                let __tag = parsing::Tag::read(value, #tag_name)?;
+                match parsing::Key::key(&__tag) {
+                    #(#arms,)*
+                    other => ::std::result::Result::Err(parsing::ParseError::new(
+                        &format!("Unknown {} '{}', expected one of: {}.", #tag_name, other, #expected),
+                        __tag.span().start
+                    ))
+                }
+            }
+        }
+        None => quote! {
+            let mut options = Vec::new();
+            let mut error: ::std::option::Option<parsing::ParseError> = ::std::option::Option::None;
+            let mut committed = false;
+            #(#parse_variants)*
+            options.sort_by(|(a, _), (b, _)| a.span().partial_cmp(&b.span()).unwrap());
+            match options.into_iter().next() {
+                ::std::option::Option::Some((option, end)) => {
+                    value.goto(end)?;
+                    ::std::result::Result::Ok(option)
                 }
-                ::std::result::Result::Err(err) => error = ::std::option::Option::Some(err)
+                ::std::option::Option::None => ::std::result::Result::Err(error.unwrap())
             }
         }
+    };
+
+    let parse_body = trace_body(ident, memoize_body(parse_body));
+
+    let name_variants = variants.clone().map(|(variant_ident, _, _, _)| quote! {
+        Self::#variant_ident { .. } => stringify!(#variant_ident),
     });
 
     let span_variants = variants.map(|(variant_ident, _, fields, _)| {
@@ -90,27 +197,31 @@ fn derive_enum(ident: &Ident, value: &DataEnum) -> TokenStream {
             }
         });
 
-        let first = inner_ident(&fields.first().unwrap().ident, 0);
-        let last = inner_ident(&fields.last().unwrap().ident, fields.len() - 1);
+        let span_body = span_body(fields.iter().enumerate().map(|(i, field)| {
+            let ident = inner_ident(&field.ident, i);
+            quote! { #ident }
+        }).collect());
 
         quote! {
-            Self::#variant_ident(#(#definitions),*) => 
-                parsing::charstream::Span::new(#first.span().start, #last.span().end),
+            Self::#variant_ident(#(#definitions),*) => { #span_body }
         }
     });
 
     quote! {
         impl #ident {
             #(#variant_functions)*
+
+            /// Returns the name of the variant that this value is.
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#name_variants)*
+                }
+            }
         }
 
         impl Parse for #ident {
             fn parse(value: &mut parsing::charstream::CharStream) -> ::std::result::Result<Self, parsing::ParseError> {
-                let mut options = Vec::new();
-                let mut error = None;
-                #(#parse_variants)*
-                options.sort_by(|a, b| a.span().partial_cmp(&b.span()).unwrap());
-                options.first().and_then(|option| Some(option.clone())).ok_or(error.unwrap())
+                #parse_body
             }
 
             fn span(&self) -> parsing::charstream::Span {
@@ -122,7 +233,7 @@ fn derive_enum(ident: &Ident, value: &DataEnum) -> TokenStream {
     }.into()
 }
 
-fn derive_variant_function(field_ident: &Ident, func_ident: Ident, fields: &Fields, _attrs: &[Attribute]) -> Result<quote::__private::TokenStream, TokenStream> {
+fn derive_variant_function(ident: &Ident, field_ident: &Ident, func_ident: Ident, fields: &Fields, _attrs: &[Attribute]) -> Result<quote::__private::TokenStream, TokenStream> {
     let definitions = derive_fields(fields.iter().collect());
     let parse_result = match fields {
         Fields::Named(fields) => {
@@ -142,22 +253,31 @@ fn derive_variant_function(field_ident: &Ident, func_ident: Ident, fields: &Fiel
         }
         Fields::Unit => return Err(TokenStream::from(Error::new(field_ident.span(), "Can not derive trait Parse for a unit variant.").to_compile_error()))
     };
+    if fields.is_empty() {
+        return Err(TokenStream::from(Error::new(field_ident.span(), "Can not derive trait Parse for a variant without fields, it needs a field for its span.").to_compile_error()));
+    }
+    let parse_body = frame_body(quote! { concat!(stringify!(#ident), "::", stringify!(#field_ident)) }, quote! {
+        #(#definitions)*
+        #parse_result
+    });
     Ok(quote! {
         fn #func_ident(value: &mut parsing::charstream::CharStream) -> ::std::result::Result<Self, parsing::ParseError> {
-            #(#definitions)*
-            #parse_result
+            #parse_body
         }
     })
 }
 
 fn derive_fields(fields: Vec<&Field>) -> Vec<quote::__private::TokenStream> {
     let fields = fields.iter().enumerate().map(|(i, field)| {
-        let field = field.clone().clone();
+        let field = (*field).clone();
         (inner_ident(&field.ident, i), field.ty, field.attrs)
     });
+    let mut committed = false;
     fields.map(|(ident, ty, attrs)| {
         let whitespace_attr = get_attr(&attrs, "whitespace");
         let value_attr = get_attr(&attrs, "value");
+        let is_committed = committed;
+        committed |= has_attr(&attrs, "commit");
 
         let value = match whitespace_attr {
             Some(attr) => {
@@ -177,13 +297,26 @@ fn derive_fields(fields: Vec<&Field>) -> Vec<quote::__private::TokenStream> {
                 <#ty>::parse(value)
             }
         };
+        let value = match is_committed {
+            true => quote! {
+                #value.map_err(parsing::ParseError::commit)
+            },
+            false => value
+        };
         let value = match value_attr {
             Some(attr) => {
                 let mut values = attr.nested.iter().map(|meta| quote! { 
                     ::std::result::Result::Ok(inner) if inner == #meta => inner
                 }).collect::<Vec<_>>();
+                let error = quote! {
+                    parsing::ParseError::new("Value was not one of the expected values.", inner.span().start)
+                };
+                let error = match is_committed {
+                    true => quote! { #error.commit() },
+                    false => error
+                };
                 values.push(quote! { 
-                    ::std::result::Result::Ok(inner) => return ::std::result::Result::Err(parsing::ParseError::new("Value was not one of the expected values.", value.position()))
+                    ::std::result::Result::Ok(inner) => return ::std::result::Result::Err(#error)
                 });
                 values.push(quote! { 
                     ::std::result::Result::Err(error) => return ::std::result::Result::Err(error)
@@ -204,6 +337,49 @@ fn derive_fields(fields: Vec<&Field>) -> Vec<quote::__private::TokenStream> {
     }).collect::<Vec<_>>()
 }
 
+fn trace_body(ident: &Ident, body: quote::__private::TokenStream) -> quote::__private::TokenStream {
+    match cfg!(feature = "trace") {
+        true => quote! {
+            value.trace(stringify!(#ident), |value| {
+                #body
+            })
+        },
+        false => body
+    }
+}
+
+fn frame_body(label: quote::__private::TokenStream, body: quote::__private::TokenStream) -> quote::__private::TokenStream {
+    match cfg!(feature = "frames") {
+        true => quote! {
+            let __start = value.position();
+            (|value: &mut parsing::charstream::CharStream| -> ::std::result::Result<Self, parsing::ParseError> {
+                #body
+            })(value).map_err(|error| {
+                let __end = error.position().clone();
+                error.frame(#label, parsing::charstream::Span::new(__start, __end))
+            })
+        },
+        false => body
+    }
+}
+
+fn memoize_body(body: quote::__private::TokenStream) -> quote::__private::TokenStream {
+    match cfg!(feature = "memoize") {
+        true => quote! {
+            value.memoize(|value| {
+                #body
+            })
+        },
+        false => body
+    }
+}
+
+fn span_body(fields: Vec<quote::__private::TokenStream>) -> quote::__private::TokenStream {
+    quote! {
+        parsing::charstream::Span::covering([#(#fields.span()),*]).unwrap()
+    }
+}
+
 fn get_attr(attrs: &Vec<Attribute>, value: &str) -> Option<MetaList> {
     attrs.iter().find_map(|attr| match attr.path.get_ident() {
         Some(ident) if ident == value => {
@@ -216,6 +392,10 @@ fn get_attr(attrs: &Vec<Attribute>, value: &str) -> Option<MetaList> {
     })
 }
 
+fn has_attr(attrs: &[Attribute], value: &str) -> bool {
+    attrs.iter().any(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == value))
+}
+
 fn inner_ident(ident: &Option<Ident>, index: usize) -> Ident {
     let ident = get_ident(ident, index);
     Ident::new(&format!("__inner_{}", ident), ident.span())
@@ -1,4 +1,27 @@
+#![allow(clippy::tabs_in_doc_comments)]
+
 pub mod parsing;
+pub mod prelude;
+mod macros;
 
+/// Derives [`parsing::Parse`] for a struct or an enum, see the parseal-derive crate for what it generates.
+/// 
+/// The span of a derived value is made from the spans of its fields, so a struct or variant without fields can not derive it:
+/// ```compile_fail
+/// # use parseal::{Parsable, parsing::{self, Parse}};
+/// #[derive(Parsable, Clone)]
+/// struct Empty {}
+/// ```
+/// The same goes for a variant without fields:
+/// ```compile_fail
+/// # use parseal::{Parsable, parsing::{self, Parse, Number}};
+/// #[derive(Parsable, Clone)]
+/// enum Value {
+/// 	Number(Number),
+/// 	Nothing {}
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use parseal_derive::Parsable;
 #[cfg(feature = "derive")]
-pub use parseal_derive::Parsable;
\ No newline at end of file
+pub use parseal_derive::ParsableKeyword;
\ No newline at end of file
@@ -0,0 +1,125 @@
+/// Parses `input` as the given type, and panics with the error if that fails.
+/// The parsed value is returned, so it can be checked further.
+/// ```
+/// # use parseal::{assert_parses, parsing::{tokens, Identifier, List, Number}};
+/// # fn main() {
+/// 	let list = assert_parses!(List<Number, tokens::Comma>, "1, 2, 3");
+/// 	assert_eq!(list.items().count(), 3);
+/// 
+/// 	assert_parses!(Identifier, "hello");
+/// # }
+/// ```
+/// ```should_panic
+/// # use parseal::{assert_parses, parsing::Number};
+/// # fn main() {
+/// 	assert_parses!(Number, "hello");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_parses {
+    ($type:ty, $input:expr) => {{
+        let mut buffer = $crate::parsing::charstream::CharStream::new(::std::string::String::from($input)).build();
+        match <$type as $crate::parsing::Parse>::parse(&mut buffer) {
+            ::std::result::Result::Ok(value) => value,
+            ::std::result::Result::Err(error) => panic!("expected {:?} to parse as `{}`, but got {:?}", $input, stringify!($type), error)
+        }
+    }};
+}
+
+/// Parses `input` as the given type, and panics if that does not fail at the expected `(row, column)`.
+/// The error is returned, so it can be checked further.
+/// ```
+/// # use parseal::{assert_parse_err, parsing::{tokens, Number, ParseErrorKind}};
+/// # fn main() {
+/// 	assert_parse_err!((Number, tokens::Comma, Number), "1, x", (0, 3));
+/// 
+/// 	let error = assert_parse_err!(tokens::EqualEqual, "=", (0, 1));
+/// 	assert_eq!(error.kind(), ParseErrorKind::UnexpectedEof);
+/// # }
+/// ```
+/// ```should_panic
+/// # use parseal::{assert_parse_err, parsing::Number};
+/// # fn main() {
+/// 	assert_parse_err!(Number, "12", (0, 0));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_parse_err {
+    ($type:ty, $input:expr, $position:expr) => {{
+        let mut buffer = $crate::parsing::charstream::CharStream::new(::std::string::String::from($input)).build();
+        match <$type as $crate::parsing::Parse>::parse(&mut buffer) {
+            ::std::result::Result::Ok(_) => panic!("expected {:?} to fail to parse as `{}`, but it parsed", $input, stringify!($type)),
+            ::std::result::Result::Err(error) => {
+                let position = (error.position().row(), error.position().column());
+                assert_eq!(position, $position, "expected {:?} to fail at a different position, the error was {:?}", $input, error);
+                error
+            }
+        }
+    }};
+}
+
+/// Parses every type at the same position of `value` (a `&mut CharStream`), and keeps the one that consumed the most input.
+/// Each type is followed by a function that turns it into the result, so the types can be different.
+/// The stream is advanced past the longest match, when two types consume the same amount, the first one wins.
+/// 
+/// When no type parses, the error that got the furthest is returned, a [committed](crate::parsing::ParseError::commit) error is returned right away.
+/// ```
+/// # use parseal::{longest_match, parsing::{charstream::CharStream, tokens, Identifier, Number}};
+/// # fn main() {
+/// 	#[derive(Debug, PartialEq)]
+/// 	enum Operator {
+/// 		Assign,
+/// 		Equals
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("== 1".to_owned()).build();
+/// 	let operator = longest_match!(&mut buffer, tokens::Equal => |_| Operator::Assign, tokens::EqualEqual => |_| Operator::Equals);
+/// 	assert_eq!(operator.unwrap(), Operator::Equals);
+/// 	assert_eq!(buffer.position().column(), 2);
+/// 
+/// 	let mut buffer = CharStream::new("= 1".to_owned()).build();
+/// 	let operator = longest_match!(&mut buffer, tokens::Equal => |_| Operator::Assign, tokens::EqualEqual => |_| Operator::Equals);
+/// 	assert_eq!(operator.unwrap(), Operator::Assign);
+/// 
+/// 	let mut buffer = CharStream::new("x".to_owned()).build();
+/// 	let word = longest_match!(&mut buffer, Identifier => |_| "first", Identifier => |_| "second");
+/// 	assert_eq!(word.unwrap(), "first");
+/// 
+/// 	let mut buffer = CharStream::new("+".to_owned()).build();
+/// 	assert!(longest_match!(&mut buffer, Number => |_| 0, Identifier => |_| 1).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! longest_match {
+    ($value:expr, $($type:ty => $constructor:expr),+ $(,)?) => {{
+        let value: &mut $crate::parsing::charstream::CharStream = $value;
+        let mut longest_match = || {
+            let mut longest = ::std::option::Option::None;
+            let mut furthest: ::std::option::Option<$crate::parsing::ParseError> = ::std::option::Option::None;
+            $(
+                let mut candidate = value.clone();
+                match <$type as $crate::parsing::Parse>::parse(&mut candidate) {
+                    ::std::result::Result::Ok(item) => {
+                        let end = candidate.position();
+                        if longest.as_ref().map_or(true, |(longest_end, _): &($crate::parsing::charstream::Position, _)| end.index() > longest_end.index()) {
+                            longest = ::std::option::Option::Some((end, ($constructor)(item)));
+                        }
+                    }
+                    ::std::result::Result::Err(error) if error.is_committed() => return ::std::result::Result::Err(error),
+                    ::std::result::Result::Err(error) => {
+                        value.record_failure(&error);
+                        if furthest.as_ref().map_or(true, |furthest| error.position().index() > furthest.position().index()) {
+                            furthest = ::std::option::Option::Some(error);
+                        }
+                    }
+                }
+            )+
+
+            match longest {
+                ::std::option::Option::Some((end, item)) => value.goto(end).map(|_| item),
+                ::std::option::Option::None => ::std::result::Result::Err(furthest.unwrap())
+            }
+        };
+        longest_match()
+    }};
+}
@@ -1,27 +1,159 @@
 pub mod tokens;
 pub mod charstream;
+pub mod convert;
+pub mod dynamic;
+pub mod expression;
+pub mod incremental;
+pub mod literals;
 
-use std::fmt;
+use std::{fmt, marker::PhantomData, rc::Rc, sync::Arc};
 
-use self::{charstream::{CharStream, Position, WhitespaceType, Span}, tokens::Delimiter};
+use self::charstream::{CharStream, Position, WhitespaceType, Span};
 
 pub trait Parse: Clone {
+	/// Parses a value at the current position of the stream.
+	/// 
+	/// When it succeeds, the stream is left directly after the value, so the same stream can be used to parse whatever follows.
+	/// This makes it possible to use a type inside of a parser that was written by hand.
+	/// When it fails, the stream might have moved, parse from a clone of the stream to be able to try something else.
 	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized;
 	fn span(&self) -> Span;
+
+	/// Parses `Self`, and then a `U` from the same stream, the stream is left after the `U`.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("12 apples, 3 pears".to_owned()).build();
+	/// 	let (count, fruit) = Number::parse_then::<Identifier>(&mut buffer).unwrap();
+	/// 	assert_eq!(count.span().start.column(), 0);
+	/// 	assert!(fruit == "apples");
+	/// 
+	/// 	// the stream continues after the identifier.
+	/// 	assert!(tokens::Comma::parse(&mut buffer).is_ok());
+	/// 	let (_, fruit) = Number::parse_then::<Identifier>(&mut buffer).unwrap();
+	/// 	assert!(fruit == "pears");
+	/// # }
+	/// ```
+	fn parse_then<U>(value: &mut CharStream) -> Result<(Self, U), ParseError> where Self: Sized, U: Parse {
+		let item = Self::parse(value)?;
+		let next = U::parse(value)?;
+		Ok((item, next))
+	}
+
+	/// Parses `Self`, and returns how many bytes of the buffer were consumed, including skipped whitespace.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Number, Parse, StringValue};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new(" 12345 rest".to_owned()).build();
+	/// 	let (_, consumed) = Number::parse_consuming(&mut buffer).unwrap();
+	/// 	assert_eq!(consumed, 6);
+	/// 
+	/// 	let mut buffer = CharStream::new("\"é\"".to_owned()).build();
+	/// 	let (_, consumed) = StringValue::parse_consuming(&mut buffer).unwrap();
+	/// 	assert_eq!(consumed, 4);
+	/// # }
+	/// ```
+	fn parse_consuming(value: &mut CharStream) -> Result<(Self, usize), ParseError> where Self: Sized {
+		let start = value.byte_offset(&value.position());
+		let item = Self::parse(value)?;
+		Ok((item, value.byte_offset(&value.position()) - start))
+	}
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseErrorKind {
+	/// The input did not match what was expected.
+	Mismatch,
+	/// The input ended before the value was complete, more input could make it parse.
+	UnexpectedEof
 }
 
 #[derive(Clone)]
-pub struct ParseError(String, Position);
+pub struct ParseError {
+	cause: String,
+	position: Position,
+	kind: ParseErrorKind,
+	committed: bool,
+	frames: Vec<(&'static str, Span)>
+}
 
 impl ParseError {
 	pub fn new(cause: &str, position: Position) -> Self {
-		Self(cause.to_string(), position)
+		Self { cause: cause.to_string(), position, kind: ParseErrorKind::Mismatch, committed: false, frames: Vec::new() }
+	}
+
+	pub fn eof(cause: &str, position: Position) -> Self {
+		Self { kind: ParseErrorKind::UnexpectedEof, ..Self::new(cause, position) }
+	}
+
+	pub fn kind(&self) -> ParseErrorKind {
+		self.kind
+	}
+
+	/// Marks the error as committed.
+	/// A committed error means the input clearly contained the value that was being parsed, 
+	/// so alternatives should not be tried anymore and this error should be reported instead.
+	pub fn commit(mut self) -> Self {
+		self.committed = true;
+		self
+	}
+
+	pub fn is_committed(&self) -> bool {
+		self.committed
+	}
+
+	pub fn position(&self) -> &Position {
+		&self.position
+	}
+
+	/// Adds a frame to the error, it says that the error happened while parsing the construct `label`, that started at the start of `span`.
+	/// Frames are added from the inside out, while the error is returned from every construct it was in.
+	/// 
+	/// With the `frames` feature, the derive macro adds a frame with the name of the type for every struct, and with the name of the variant for every enum variant.
+	/// Without it, no frames are collected, so failed alternatives do not cost anything extra.
+	pub fn frame(mut self, label: &'static str, span: Span) -> Self {
+		self.frames.push((label, span));
+		self
+	}
+
+	/// Returns the frames of the error, from the outermost construct to the innermost.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Group, Identifier, List, Number, Parse};
+	/// # fn main() {
+	/// 	#[cfg(feature="frames")]
+	/// 	{
+	/// 		# use parseal::{Parsable, parsing::{self, StringValue}};
+	/// 		#[derive(Parsable, Clone)]
+	/// 		struct Entry(StringValue, #[commit] tokens::Colon, Value);
+	/// 
+	/// 		#[derive(Parsable, Clone)]
+	/// 		struct Object(#[commit] tokens::LeftBrace, List<Entry, tokens::Comma>, tokens::RightBrace);
+	/// 
+	/// 		#[derive(Parsable, Clone)]
+	/// 		enum Value {
+	/// 			Object(Box<Object>),
+	/// 			Number(Number)
+	/// 		}
+	/// 
+	/// 		let mut buffer = CharStream::new("{\"a\": {\"b\": }}".to_owned()).build();
+	/// 		let error = Value::parse(&mut buffer).err().unwrap();
+	/// 		let labels = error.frames().map(|(label, _)| label).collect::<Vec<_>>();
+	/// 		assert_eq!(labels, ["Value::Object", "Object", "Entry", "Value::Object", "Object", "Entry", "Value::Number"]);
+	/// 
+	/// 		let trace = labels.iter().map(|label| format!("in {}", label)).collect::<Vec<_>>().join(" -> ");
+	/// 		assert!(trace.starts_with("in Value::Object -> in Object -> in Entry"));
+	/// 	}
+	/// # }
+	/// ```
+	pub fn frames(&self) -> impl Iterator<Item = (&'static str, &Span)> {
+		self.frames.iter().rev().map(|(label, span)| (*label, span))
 	}
 }
 
 impl fmt::Debug for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "{}:{}:Error: '{}'", self.1.row, self.1.column, self.0)
+		write!(f, "{}:{}:Error: '{}'", self.position.row(), self.position.column(), self.cause)
     }
 }
 
@@ -30,7 +162,7 @@ impl fmt::Debug for ParseError {
 /// - `D` is the delimiter tokens around the item, it has to a type that implements [`tokens::Delimiter`].
 /// - `I` is the type of item inside the delimiters, it has to implement [`Parse`].
 /// ```
-/// # use parseal::parsing::{charstream::CharStream, tokens, Group, StringValue, Number, List, Parse};
+/// # use parseal::parsing::{charstream::CharStream, tokens, Group, Identifier, StringValue, Number, List, Parse};
 /// # fn main() {
 /// 	let buffer = "(\"Hello, World\")".to_owned();
 /// 	let mut buffer = CharStream::new(buffer).build();
@@ -43,12 +175,45 @@ impl fmt::Debug for ParseError {
 /// 
 /// 	let value = Group::<tokens::Bracket, List<Number, tokens::Comma>>::parse(&mut buffer);
 /// 	assert!(value.is_ok());
+/// 
+/// 	let buffer = "[[0]]".to_owned();
+/// 	let mut buffer = CharStream::new(buffer).max_depth(1).build();
+/// 
+/// 	let value = Group::<tokens::Bracket, Group<tokens::Bracket, Number>>::parse(&mut buffer);
+/// 	assert!(value.is_err());
+/// 	// the maximum depth limits how deeply groups can be nested.
+/// 
+/// 	let mut buffer = CharStream::new("(((x)))".to_owned()).build();
+/// 	let value = Group::<tokens::Paren, Group<tokens::Paren, Group<tokens::Paren, Identifier>>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.depth(), 3);
+/// 	assert_eq!(value.item().depth(), 2);
+/// 
+/// 	let mut buffer = CharStream::new("((x)".to_owned()).build();
+/// 	let error = Group::<tokens::Paren, Group<tokens::Paren, Identifier>>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:4:Error: 'Expected ')' to close the '(' from 0:0.'");
 /// # }
 /// ```
 #[derive(Clone)]
 pub struct Group<D, I> where D: tokens::Delimiter, I: Parse {
 	delimiter: D,
-	item: I
+	item: I,
+	depth: usize
+}
+
+impl<D, I> Group<D, I> where
+	D: tokens::Delimiter,
+	I: Parse
+{
+	/// Returns the item between the delimiters.
+	pub fn item(&self) -> &I {
+		&self.item
+	}
+
+	/// Returns how deeply groups are nested in this group, counting itself.
+	/// A group without groups inside of it has a depth of 1.
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
 }
 
 impl<D, I> Parse for Group<D, I> where
@@ -57,15 +222,19 @@ impl<D, I> Parse for Group<D, I> where
 {
     fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
 		let start = D::Start::parse(value)?;
-		let item = I::parse(value)?;
-		let end = match D::End::parse(value) {
-			Ok(value) => value,
-			Err(error) => return Err(error)
-		};
+
+		let ((item, end), depth) = value.nested(|value| {
+			let item = I::parse(value)?;
+			let end = D::End::parse(value).map_err(|error| ParseError {
+				cause: format!("Expected '{}' to close the '{}' from {}.", D::close(), D::open(), start.span().start),
+				..error
+			})?;
+			Ok((item, end))
+		})?;
 
 		let delimiter = D::new(start, end);
 
-		Ok(Self { delimiter, item })
+		Ok(Self { delimiter, item, depth })
     }
 
 	fn span(&self) -> Span {
@@ -82,11 +251,497 @@ impl<D, I> fmt::Debug for Group<D, I> where
     }
 }
 
+/// The kind of delimiter that an [`AnyGroup`] was surrounded by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DelimiterKind {
+	/// `(` and `)`.
+	Paren,
+	/// `[` and `]`.
+	Bracket,
+	/// `{` and `}`.
+	Brace
+}
+
+impl DelimiterKind {
+	/// The text of the opening delimiter.
+	pub fn open(&self) -> &'static str {
+		match self {
+			Self::Paren => "(",
+			Self::Bracket => "[",
+			Self::Brace => "{"
+		}
+	}
+
+	/// The text of the closing delimiter.
+	pub fn close(&self) -> &'static str {
+		match self {
+			Self::Paren => ")",
+			Self::Bracket => "]",
+			Self::Brace => "}"
+		}
+	}
+}
+
+/// AnyGroup is a [`Group`] that can be surrounded by parentheses, brackets or braces, and remembers which it was.
+/// The delimiters are tried in that order, once an opening delimiter is found, the group has to be closed by the matching one.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, AnyGroup, DelimiterKind, List, Number, Parse};
+/// # fn main() {
+/// 	type Args = AnyGroup<List<Number, tokens::Comma>>;
+/// 
+/// 	for (input, kind) in [("(1, 2)", DelimiterKind::Paren), ("[1, 2]", DelimiterKind::Bracket), ("{1, 2}", DelimiterKind::Brace)] {
+/// 		let mut buffer = CharStream::new(input.to_owned()).build();
+/// 		let args = Args::parse(&mut buffer).unwrap();
+/// 		assert_eq!(args.kind(), kind);
+/// 		assert_eq!(args.item().items().count(), 2);
+/// 		assert_eq!(args.span().end.column(), 6);
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("(1, 2]".to_owned()).build();
+/// 	let error = Args::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:5:Error: 'Expected ')' to close the '(' from 0:0.'");
+/// 
+/// 	let mut buffer = CharStream::new("<1, 2>".to_owned()).build();
+/// 	let error = Args::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'Expected one of '(', '[' or '{'.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AnyGroup<I> where I: Parse {
+	kind: DelimiterKind,
+	item: I,
+	span: Span,
+	depth: usize
+}
+
+impl<I> AnyGroup<I> where I: Parse {
+	/// Returns the item between the delimiters.
+	pub fn item(&self) -> &I {
+		&self.item
+	}
+
+	/// Returns the kind of delimiter that surrounded the item.
+	pub fn kind(&self) -> DelimiterKind {
+		self.kind
+	}
+
+	/// Returns how deeply groups are nested in this group, counting itself, see [`Group::depth`].
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+
+	fn group<D>(value: &mut CharStream, kind: DelimiterKind) -> Result<Self, ParseError> where D: tokens::Delimiter {
+		let group = Group::<D, I>::parse(value)?;
+		Ok(Self { kind, span: group.span(), depth: group.depth, item: group.item })
+	}
+}
+
+impl<I> Parse for AnyGroup<I> where I: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		if tokens::LeftParen::parse(&mut value.clone()).is_ok() {
+			return Self::group::<tokens::Paren>(value, DelimiterKind::Paren);
+		}
+		if tokens::LeftBracket::parse(&mut value.clone()).is_ok() {
+			return Self::group::<tokens::Bracket>(value, DelimiterKind::Bracket);
+		}
+		if tokens::LeftBrace::parse(&mut value.clone()).is_ok() {
+			return Self::group::<tokens::Brace>(value, DelimiterKind::Brace);
+		}
+
+		let mut group_value = value.clone();
+		group_value.skip_whitespace();
+		let cause = "Expected one of '(', '[' or '{'.";
+		match group_value.is_at_end() {
+			true => Err(ParseError::eof(cause, group_value.position())),
+			false => Err(ParseError::new(cause, group_value.position()))
+		}
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<I> fmt::Debug for AnyGroup<I> where I: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "AnyGroup({:#?}, delim: {:?}, from {})", self.item, self.kind, self.span)
+    }
+}
+
+/// LenientGroup is a [`Group`] that does not need to be closed at the end of the input.
+/// When the input ends where the closing delimiter was expected, the group is returned without it,
+/// which is useful to show the structure of input that is still being typed.
+/// Any other missing closing delimiter is still an error.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Group, Identifier, LenientGroup, List, Number, Parse};
+/// # fn main() {
+/// 	type Array = LenientGroup<tokens::Bracket, List<Number, tokens::Comma>>;
+/// 
+/// 	let mut buffer = CharStream::new("[1, 2".to_owned()).build();
+/// 	let array = Array::parse(&mut buffer).unwrap();
+/// 	assert!(!array.is_closed());
+/// 	assert_eq!(array.item().items().count(), 2);
+/// 	assert_eq!(array.span().end.column(), 5);
+/// 
+/// 	let mut buffer = CharStream::new("[1, 2]".to_owned()).build();
+/// 	assert!(Array::parse(&mut buffer).unwrap().is_closed());
+/// 
+/// 	let mut buffer = CharStream::new("[1, 2 3]".to_owned()).build();
+/// 	assert!(Array::parse(&mut buffer).is_err());
+/// 
+/// 	let mut buffer = CharStream::new("((x))".to_owned()).build();
+/// 	let value = Group::<tokens::Paren, LenientGroup<tokens::Paren, Identifier>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.depth(), 2);
+/// 	assert_eq!(value.item().depth(), 1);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LenientGroup<D, I> where D: tokens::Delimiter, I: Parse {
+	start: D::Start,
+	item: I,
+	end: Option<D::End>,
+	depth: usize
+}
+
+impl<D, I> LenientGroup<D, I> where
+	D: tokens::Delimiter,
+	I: Parse
+{
+	/// Returns the item between the delimiters.
+	pub fn item(&self) -> &I {
+		&self.item
+	}
+
+	/// Returns false if the input ended before the closing delimiter.
+	pub fn is_closed(&self) -> bool {
+		self.end.is_some()
+	}
+
+	/// Returns how deeply groups are nested in this group, counting itself, see [`Group::depth`].
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+}
+
+impl<D, I> Parse for LenientGroup<D, I> where
+	D: tokens::Delimiter,
+	I: Parse
+{
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let start = D::Start::parse(value)?;
+
+		let ((item, end), depth) = value.nested(|value| {
+			let item = I::parse(value)?;
+			let end = match D::End::parse(value) {
+				Ok(end) => Some(end),
+				Err(error) if error.kind() == ParseErrorKind::UnexpectedEof => None,
+				Err(error) => return Err(ParseError {
+					cause: format!("Expected '{}' to close the '{}' from {}.", D::close(), D::open(), start.span().start),
+					..error
+				})
+			};
+			Ok((item, end))
+		})?;
+
+		Ok(Self { start, item, end, depth })
+    }
+
+	fn span(&self) -> Span {
+		match &self.end {
+			Some(end) => Span::new(self.start.span().start, end.span().end),
+			None => Span::new(self.start.span().start, self.item.span().end)
+		}
+	}
+}
+
+impl<D, I> fmt::Debug for LenientGroup<D, I> where
+	D: tokens::Delimiter,
+	I: Parse + fmt::Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "LenientGroup({:#?}, delim: {}, closed: {}, from {})", self.item, D::name(), self.is_closed(), self.span())
+    }
+}
+
+/// Delimited is an item between two tokens, like a [`Group`], 
+/// but the tokens do not have to be a [`tokens::Delimiter`] pair, and they are kept as fields.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Delimited, Identifier, Parse};
+/// # fn main() {
+/// 	type Generic = Delimited<tokens::Less, Identifier, tokens::Greater>;
+/// 
+/// 	let mut buffer = CharStream::new("<T>".to_owned()).build();
+/// 	let generic = Generic::parse(&mut buffer).unwrap();
+/// 	assert!(*generic.item() == "T");
+/// 	assert_eq!(generic.open().span().start.column(), 0);
+/// 	assert_eq!(generic.close().span().start.column(), 2);
+/// 	assert_eq!(generic.span().end.column(), 3);
+/// 
+/// 	let mut buffer = CharStream::new("<T".to_owned()).build();
+/// 	assert!(Generic::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Delimited<Open, I, Close> where Open: tokens::Token, I: Parse, Close: tokens::Token {
+	open: Open,
+	item: I,
+	close: Close
+}
+
+impl<Open, I, Close> Delimited<Open, I, Close> where
+	Open: tokens::Token,
+	I: Parse,
+	Close: tokens::Token
+{
+	pub fn open(&self) -> &Open {
+		&self.open
+	}
+
+	/// Returns the item between the tokens.
+	pub fn item(&self) -> &I {
+		&self.item
+	}
+
+	pub fn close(&self) -> &Close {
+		&self.close
+	}
+}
+
+impl<Open, I, Close> Parse for Delimited<Open, I, Close> where
+	Open: tokens::Token,
+	I: Parse,
+	Close: tokens::Token
+{
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let open = Open::parse(value)?;
+
+		value.descend()?;
+		let item = I::parse(value);
+		value.ascend();
+
+		let item = item?;
+		let close = Close::parse(value)?;
+
+		Ok(Self { open, item, close })
+    }
+
+	fn span(&self) -> Span {
+		Span::new(self.open.span().start, self.close.span().end)
+	}
+}
+
+impl<Open, I, Close> fmt::Debug for Delimited<Open, I, Close> where
+	Open: tokens::Token + fmt::Debug,
+	I: Parse + fmt::Debug,
+	Close: tokens::Token + fmt::Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Delimited({:?}, {:#?}, {:?})", self.open, self.item, self.close)
+    }
+}
+
+
+/// A Pair is a key and a value, separated by a token, like `a: 1`.
+/// A [`List`] of pairs with a [`Key`] can be queried with [`List::get`].
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, List, Number, Pair, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a: 1".to_owned()).build();
+/// 	let pair = Pair::<Identifier, tokens::Colon, Number>::parse(&mut buffer).unwrap();
+/// 	assert!(*pair.key() == "a");
+/// 	assert_eq!(pair.value().span().start.column(), 3);
+/// 	assert_eq!(pair.span().start.column(), 0);
+/// 	assert_eq!(pair.span().end.column(), 4);
+/// 
+/// 	let mut buffer = CharStream::new("a: 1, b: 2".to_owned()).build();
+/// 	let object = List::<Pair<Identifier, tokens::Colon, Number>, tokens::Comma>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(object.get("b").unwrap().span().start.column(), 9);
+/// 
+/// 	let mut buffer = CharStream::new("a 1".to_owned()).build();
+/// 	assert!(Pair::<Identifier, tokens::Colon, Number>::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Pair<K, Sep, V> where K: Parse, Sep: tokens::Token, V: Parse {
+	key: K,
+	separator: Sep,
+	value: V
+}
+
+impl<K, Sep, V> Pair<K, Sep, V> where
+	K: Parse,
+	Sep: tokens::Token,
+	V: Parse
+{
+	pub fn key(&self) -> &K {
+		&self.key
+	}
+
+	pub fn separator(&self) -> &Sep {
+		&self.separator
+	}
+
+	pub fn value(&self) -> &V {
+		&self.value
+	}
+}
+
+impl<K, Sep, V> Parse for Pair<K, Sep, V> where
+	K: Parse,
+	Sep: tokens::Token,
+	V: Parse
+{
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let key = K::parse(value)?;
+		let separator = Sep::parse(value)?;
+		let item = V::parse(value)?;
+
+		Ok(Self { key, separator, value: item })
+    }
+
+	fn span(&self) -> Span {
+		Span::new(self.key.span().start, self.value.span().end)
+	}
+}
+
+impl<K, Sep, V> Entry for Pair<K, Sep, V> where
+	K: Parse + Key,
+	Sep: tokens::Token,
+	V: Parse
+{
+	type Value = V;
+
+	fn key(&self) -> &str {
+		self.key.key()
+	}
+
+	fn value(&self) -> &Self::Value {
+		&self.value
+	}
+}
+
+impl<K, Sep, V> fmt::Debug for Pair<K, Sep, V> where
+	K: Parse + fmt::Debug,
+	Sep: tokens::Token + fmt::Debug,
+	V: Parse + fmt::Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Pair({:?}, {:?}, {:#?})", self.key, self.separator, self.value)
+    }
+}
+
+
+/// A Range is two bounds around `..` or `..=`, like `1..5` or `a..=z`.
+/// Either bound can be left out, like `..5` or `1..`, but a range with `..=` needs an end.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Identifier, Number, Parse, Range};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("1..5".to_owned()).build();
+/// 	let range = Range::<Number>::parse(&mut buffer).unwrap();
+/// 	assert!(range.start().is_some() && range.end().is_some());
+/// 	assert!(!range.is_inclusive());
+/// 	assert_eq!(range.span().end.column(), 4);
+/// 
+/// 	let mut buffer = CharStream::new("a..=z".to_owned()).build();
+/// 	let range = Range::<Identifier>::parse(&mut buffer).unwrap();
+/// 	assert!(*range.end().unwrap() == "z");
+/// 	assert!(range.is_inclusive());
+/// 
+/// 	let mut buffer = CharStream::new("..5".to_owned()).build();
+/// 	let range = Range::<Number>::parse(&mut buffer).unwrap();
+/// 	assert!(range.start().is_none() && range.end().is_some());
+/// 
+/// 	let mut buffer = CharStream::new("1..".to_owned()).build();
+/// 	let range = Range::<Number>::parse(&mut buffer).unwrap();
+/// 	assert!(range.start().is_some() && range.end().is_none());
+/// 
+/// 	let mut buffer = CharStream::new("1..=".to_owned()).build();
+/// 	let error = Range::<Number>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:4:Error: 'Expected an end after '..=', an inclusive range needs one.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Range<T> where T: Parse {
+	start: Option<T>,
+	end: Option<T>,
+	inclusive: bool,
+	span: Span
+}
+
+impl<T> Range<T> where T: Parse {
+	pub fn start(&self) -> Option<&T> {
+		self.start.as_ref()
+	}
+
+	pub fn end(&self) -> Option<&T> {
+		self.end.as_ref()
+	}
+
+	/// Returns true when the range includes its end, which means it was written with `..=`.
+	pub fn is_inclusive(&self) -> bool {
+		self.inclusive
+	}
+
+	fn bound(value: &mut CharStream) -> Option<T> {
+		let mut bound_value = value.clone();
+		match T::parse(&mut bound_value) {
+			Ok(bound) => {
+				value.goto(bound_value.position()).ok()?;
+				Some(bound)
+			}
+			Err(error) => {
+				value.record_failure(&error);
+				None
+			}
+		}
+	}
+}
+
+impl<T> Parse for Range<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let start = Self::bound(value);
+
+		let mut inclusive_value = value.clone();
+		let (inclusive, operator) = match tokens::DotDotEq::parse(&mut inclusive_value) {
+			Ok(operator) => {
+				value.goto(inclusive_value.position())?;
+				(true, operator.span())
+			}
+			Err(_) => (false, tokens::DotDot::parse(value)?.span())
+		};
+
+		let end = Self::bound(value);
+		if inclusive && end.is_none() {
+			return Err(ParseError::new("Expected an end after '..=', an inclusive range needs one.", value.peek_position()));
+		}
+
+		let span = Span::new(
+			start.as_ref().map_or(operator.start.clone(), |start| start.span().start),
+			end.as_ref().map_or(operator.end, |end| end.span().end)
+		);
+		Ok(Self { start, end, inclusive, span })
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<T> fmt::Debug for Range<T> where T: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let operator = match self.inclusive {
+			true => "..=",
+			false => ".."
+		};
+		write!(f, "Range({:?}, {}, {:?})", self.start, operator, self.end)
+    }
+}
 
 /// A List represents a collection of items, separated by a token.
-/// It has two generic types:
+/// It has three generic types:
 /// - `I` is the type of item, it has to implement [`Parse`].
 /// - `S` is the token that separates the items. it has to implement [`tokens::Token`].
+/// - `O` are the options of the list, it has to implement [`ListOptions`]. This can be left out to use [`Separated`].
 /// ```
 /// # use parseal::parsing::{charstream::CharStream, tokens, Group, StringValue, Number, List, Parse};
 /// # fn main() {
@@ -113,299 +768,2829 @@ impl<D, I> fmt::Debug for Group<D, I> where
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct List<I, S> where I: Parse, S: tokens::Token {
+pub struct List<I, S, O = Separated> where I: Parse, S: tokens::Token, O: ListOptions {
 	items: Vec<(I, Option<S>)>,
-	span: Span
+	span: Span,
+	options: PhantomData<O>
 }
 
-impl<I, S> Parse for List<I, S> where
-	I: Parse,
-	S: tokens::Token
-{
-	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-        let mut items = Vec::new();
-		let start = value.position();
-
-		loop {
-			let item = match I::parse(value) {
-				Ok(value) => value,
-				Err(error) => {
-					if items.len() > 0 {
-						return Err(error);
-					}
-					break
+/// ListOptions changes how a [`List`] is parsed.
+/// Every option has a default, so an implementation only has to set the options it changes.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, List, ListOptions, Parse, SingleLine};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a, b\nc".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Comma, SingleLine>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 2);
+/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "c");
+/// 
+/// 	let mut buffer = CharStream::new("a,\nb".to_owned()).build();
+/// 	let error = List::<Identifier, tokens::Comma, SingleLine>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:1:Error: 'Expected the next item of the list on the same line as the separator.'");
+/// 
+/// 	let mut buffer = CharStream::new("a, b, c".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Comma, SingleLine>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// 
+/// 	let mut buffer = CharStream::new("a, b\n, c".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Comma>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// # }
+/// ```
+/// A list can also have a maximum amount of items, and either stop or fail when there are more:
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, ExcessPolicy, List, ListOptions, Number, Parse};
+/// # fn main() {
+/// 	#[derive(Clone)]
+/// 	struct AtMostFour;
+/// 
+/// 	impl ListOptions for AtMostFour {
+/// 		const MAX_ITEMS: Option<usize> = Some(4);
+/// 	}
+/// 
+/// 	#[derive(Clone)]
+/// 	struct NoMoreThanFour;
+/// 
+/// 	impl ListOptions for NoMoreThanFour {
+/// 		const MAX_ITEMS: Option<usize> = Some(4);
+/// 		const EXCESS: ExcessPolicy = ExcessPolicy::ErrorOnExcess;
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("1, 2, 3, 4, 5".to_owned()).build();
+/// 	let list = List::<Number, tokens::Comma, AtMostFour>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 4);
+/// 	assert!(tokens::Comma::parse(&mut buffer).is_ok());
+/// 
+/// 	let mut buffer = CharStream::new("1, 2, 3, 4, 5".to_owned()).build();
+/// 	let error = List::<Number, tokens::Comma, NoMoreThanFour>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:12:Error: 'A list can not have more than 4 items.'");
+/// 
+/// 	let mut buffer = CharStream::new("1, 2, 3, 4".to_owned()).build();
+/// 	let list = List::<Number, tokens::Comma, NoMoreThanFour>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 4);
+/// 
+/// 	#[derive(Clone)]
+/// 	struct AtMost<const MAX: usize>;
+/// 
+/// 	impl<const MAX: usize> ListOptions for AtMost<MAX> {
+/// 		const MAX_ITEMS: Option<usize> = Some(MAX);
+/// 	}
+/// 
+/// 	#[derive(Clone)]
+/// 	struct NoMoreThan<const MAX: usize>;
+/// 
+/// 	impl<const MAX: usize> ListOptions for NoMoreThan<MAX> {
+/// 		const MAX_ITEMS: Option<usize> = Some(MAX);
+/// 		const EXCESS: ExcessPolicy = ExcessPolicy::ErrorOnExcess;
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("1, 2".to_owned()).build();
+/// 	let list = List::<Number, tokens::Comma, AtMost<0>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 0);
+/// 	assert!(Number::parse(&mut buffer).is_ok());
+/// 
+/// 	let mut buffer = CharStream::new("1, 2".to_owned()).build();
+/// 	let error = List::<Number, tokens::Comma, NoMoreThan<0>>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'A list can not have more than 0 items.'");
+/// 
+/// 	let mut buffer = CharStream::new("1, 2".to_owned()).build();
+/// 	let list = List::<Number, tokens::Comma, AtMost<1>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 1);
+/// 	assert!(tokens::Comma::parse(&mut buffer).is_ok());
+/// 
+/// 	let mut buffer = CharStream::new("1, 2".to_owned()).build();
+/// 	let error = List::<Number, tokens::Comma, NoMoreThan<1>>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:3:Error: 'A list can not have more than 1 items.'");
+/// 
+/// 	let mut buffer = CharStream::new("1".to_owned()).build();
+/// 	let list = List::<Number, tokens::Comma, NoMoreThan<1>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 1);
+/// # }
+/// ```
+/// With [`SoftSeparated`], a separator can be left out as long as another item follows:
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, List, Parse, SoftSeparated};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a b, c".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Comma, SoftSeparated>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// 
+/// 	let mut buffer = CharStream::new("a b, c".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Comma>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 1);
+/// # }
+/// ```
+/// With [`Terminated`], the separator ends every item instead of going between them, like the `;` after statements:
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, List, Parse, Terminated};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a; b; c;".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Semicolon, Terminated>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// 	assert!(list.pairs().all(|(_, terminator)| terminator.is_some()));
+/// 
+/// 	let mut buffer = CharStream::new("a; b; c".to_owned()).build();
+/// 	let error = List::<Identifier, tokens::Semicolon, Terminated>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:7:Error: 'Expected every item of the list to end with a separator.'");
+/// 
+/// 	let mut buffer = CharStream::new("a; b; c".to_owned()).build();
+/// 	let list = List::<Identifier, tokens::Semicolon>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// 
+/// 	// an item that is only partially there ends the list before it, and is not consumed.
+/// 	let mut buffer = CharStream::new("a b; c }".to_owned()).build();
+/// 	let list = List::<(Identifier, Identifier), tokens::Semicolon, Terminated>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 1);
+/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "c");
+/// # }
+/// ```
+pub trait ListOptions: Clone {
+	/// When this is true, a newline ends the list, so all items have to be on the same line.
+	/// A newline right after a separator is an error, because the item after it would be on the next line.
+	const SINGLE_LINE: bool = false;
+	/// The maximum amount of items, by default there is no maximum.
+	const MAX_ITEMS: Option<usize> = None;
+	/// What happens when there are more items than [`ListOptions::MAX_ITEMS`].
+	const EXCESS: ExcessPolicy = ExcessPolicy::StopAtMax;
+	/// When this is true, a missing separator does not end the list if the next item can still be parsed.
+	const SOFT_SEPARATORS: bool = false;
+	/// When this is true, every item has to be followed by the separator, including the last one.
+	const TERMINATED: bool = false;
+}
+
+/// What a [`List`] does when it has reached its maximum amount of items, but more follow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExcessPolicy {
+	/// The list ends at the last allowed item, the rest is left to be parsed by something else.
+	StopAtMax,
+	/// The list returns an error at the first item that is too many.
+	ErrorOnExcess
+}
+
+/// The default options of a [`List`], items are separated by the separator and can be spread over multiple lines.
+#[derive(Debug, Clone)]
+pub struct Separated;
+
+impl ListOptions for Separated {}
+
+/// Options for a [`List`] that has to be on a single line, a newline ends the list.
+#[derive(Debug, Clone)]
+pub struct SingleLine;
+
+impl ListOptions for SingleLine {
+	const SINGLE_LINE: bool = true;
+}
+
+/// Options for a [`List`] where separators are optional, two items can follow each other without one.
+#[derive(Debug, Clone)]
+pub struct SoftSeparated;
+
+impl ListOptions for SoftSeparated {
+	const SOFT_SEPARATORS: bool = true;
+}
+
+/// Options for a [`List`] where the separator ends every item, like `a; b; c;`.
+#[derive(Debug, Clone)]
+pub struct Terminated;
+
+impl ListOptions for Terminated {
+	const TERMINATED: bool = true;
+}
+
+impl<I, S, O> List<I, S, O> where
+	I: Parse,
+	S: tokens::Token,
+	O: ListOptions
+{
+	/// Iterates over the items of the list.
+	pub fn items(&self) -> impl Iterator<Item = &I> {
+		self.items.iter().map(|(item, _)| item)
+	}
+
+	/// Iterates over the items of the list, together with the separator that followed them.
+	/// The separator is `None` for the last item, unless the list had a trailing separator.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, List, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("1, 2, 3".to_owned()).build();
+	/// 	let list = List::<Number, tokens::Comma>::parse(&mut buffer).unwrap();
+	/// 
+	/// 	let pairs = list.pairs().collect::<Vec<_>>();
+	/// 	assert_eq!(pairs.len(), 3);
+	/// 	assert!(pairs[0].1.is_some());
+	/// 	assert!(pairs[1].1.is_some());
+	/// 	assert!(pairs[2].1.is_none());
+	/// # }
+	/// ```
+	pub fn pairs(&self) -> impl Iterator<Item = (&I, Option<&S>)> {
+		self.items.iter().map(|(item, separator)| (item, separator.as_ref()))
+	}
+
+	/// Returns an error at the next item if the list already has `max` items and should not allow more,
+	/// when `separated` is true, the item has to follow a separator to count.
+	fn check_excess(value: &CharStream, max: usize, separated: bool) -> Result<(), ParseError> {
+		if O::EXCESS != ExcessPolicy::ErrorOnExcess {
+			return Ok(());
+		}
+
+		let mut excess_value = value.clone();
+		if separated && S::parse(&mut excess_value).is_err() {
+			return Ok(());
+		}
+
+		match I::parse(&mut excess_value.clone()) {
+			Ok(_) => Err(ParseError::new(&format!("A list can not have more than {} items.", max), excess_value.peek_position())),
+			Err(_) => Ok(())
+		}
+	}
+}
+
+impl<I, S, O> List<I, S, O> where
+	I: Parse + Entry,
+	S: tokens::Token,
+	O: ListOptions
+{
+	/// Returns the value of the first entry with the given key.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, List, Number, StringValue, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("\"x\": 1, \"name\": 2".to_owned()).build();
+	/// 	let object = List::<(StringValue, tokens::Colon, Number), tokens::Comma>::parse(&mut buffer).unwrap();
+	/// 
+	/// 	assert!(object.get("name").is_some());
+	/// 	assert!(object.get("other").is_none());
+	/// 	assert_eq!(object.keys().collect::<Vec<_>>(), vec!["x", "name"]);
+	/// # }
+	/// ```
+	pub fn get(&self, key: &str) -> Option<&I::Value> {
+		self.items().find(|item| item.key() == key).map(|item| item.value())
+	}
+
+	/// Iterates over the keys of all entries.
+	pub fn keys(&self) -> impl Iterator<Item = &str> {
+		self.items().map(|item| item.key())
+	}
+}
+
+/// A SepPath is one or more `Seg`, separated by `Sep`, like the module path `std::collections::HashMap`.
+/// 
+/// Unlike a [`List`], a SepPath can not be empty, and a separator has to be followed by another segment.
+/// A separator that is not followed by a segment is not part of the path, so `a::{b}` parses only `a`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, Parse, SepPath};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("std::collections::HashMap".to_owned()).build();
+/// 	let path = SepPath::<Identifier, tokens::DoubleColon>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(path.segments().count(), 3);
+/// 	assert!(*path.last() == "HashMap");
+/// 	assert_eq!(path.span().end.column(), 25);
+/// 
+/// 	let mut buffer = CharStream::new("a::{b}".to_owned()).build();
+/// 	let path = SepPath::<Identifier, tokens::DoubleColon>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(path.segments().count(), 1);
+/// 	assert_eq!(buffer.position().column(), 1);
+/// 
+/// 	let mut buffer = CharStream::new("::a".to_owned()).build();
+/// 	assert!(SepPath::<Identifier, tokens::DoubleColon>::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SepPath<Seg, Sep> where Seg: Parse, Sep: tokens::Token {
+	first: Seg,
+	rest: Vec<(Sep, Seg)>
+}
+
+impl<Seg, Sep> SepPath<Seg, Sep> where Seg: Parse, Sep: tokens::Token {
+	/// Iterates over the segments of the path, from first to last.
+	pub fn segments(&self) -> impl Iterator<Item = &Seg> {
+		std::iter::once(&self.first).chain(self.rest.iter().map(|(_, segment)| segment))
+	}
+
+	/// Returns the last segment, for a path like `a::b::C` this is `C`.
+	pub fn last(&self) -> &Seg {
+		self.rest.last().map_or(&self.first, |(_, segment)| segment)
+	}
+}
+
+impl<Seg, Sep> Parse for SepPath<Seg, Sep> where Seg: Parse, Sep: tokens::Token {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let first = Seg::parse(value)?;
+
+		let mut rest = Vec::new();
+		loop {
+			let mut segment_value = value.clone();
+			match <(Sep, Seg)>::parse(&mut segment_value) {
+				Ok(segment) => {
+					value.goto(segment_value.position())?;
+					rest.push(segment);
+				}
+				Err(error) if error.is_committed() => return Err(error),
+				Err(error) => {
+					value.record_failure(&error);
+					break;
+				}
+			}
+		}
+
+		Ok(Self { first, rest })
+    }
+
+    fn span(&self) -> Span {
+        Span::new(self.first.span().start, self.last().span().end)
+    }
+}
+
+impl<Seg, Sep> fmt::Debug for SepPath<Seg, Sep> where Seg: Parse + fmt::Debug, Sep: tokens::Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("SepPath").field(&self.segments().collect::<Vec<_>>()).finish()
+    }
+}
+
+/// Key is a value that can be used to look up an [`Entry`].
+pub trait Key {
+	fn key(&self) -> &str;
+}
+
+impl Key for StringValue {
+	fn key(&self) -> &str {
+		&self.value
+	}
+}
+
+impl Key for Identifier {
+	fn key(&self) -> &str {
+		&self.identifier
+	}
+}
+
+/// A Tag is a keyword that decides what follows it, it is either an [`Identifier`] or a [`StringValue`].
+/// 
+/// The derive macro uses it for enums with a `#[tag("name")]` attribute, through [`Tag::read`].
+/// Instead of trying every variant in order, it reads the tag and parses the variant with a matching `#[variant(...)]` attribute directly.
+/// The tag is either a leading discriminator, like `circle, 5`, or the value of a key with the name of the attribute, like `"type": "circle", "radius": 5`.
+/// The tag is not consumed before the variant is parsed, so the variant can include it (and its key) as fields.
+/// The name in the `tag` attribute is also used in the error for an unknown tag.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Group, Key, Parse, Tag};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("\"circle\"".to_owned()).build();
+/// 	let tag = Tag::parse(&mut buffer).unwrap();
+/// 	assert_eq!(tag.key(), "circle");
+/// 
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{Parsable, parsing::{self, Identifier, Number, StringValue, tokens}};
+/// 		#[derive(Parsable, Clone)]
+/// 		#[tag("type")]
+/// 		enum Shape {
+/// 			#[variant("circle")]
+/// 			Circle(StringValue, tokens::Comma, Number),
+/// 			#[variant("rect", "rectangle")]
+/// 			Rect(StringValue, tokens::Comma, Number, tokens::Comma, Number)
+/// 		}
+/// 
+/// 		let mut buffer = CharStream::new("\"circle\", 5".to_owned()).build();
+/// 		assert!(matches!(Shape::parse(&mut buffer), Ok(Shape::Circle(..))));
+/// 
+/// 		let mut buffer = CharStream::new("\"rectangle\", 1, 2".to_owned()).build();
+/// 		assert!(matches!(Shape::parse(&mut buffer), Ok(Shape::Rect(..))));
+/// 
+/// 		let mut buffer = CharStream::new("\"square\", 3".to_owned()).build();
+/// 		let error = Shape::parse(&mut buffer).err().unwrap();
+/// 		assert_eq!(format!("{:?}", error), "0:0:Error: 'Unknown type 'square', expected one of: \"circle\", \"rect\", \"rectangle\".'");
+/// 
+/// 		type Field<V> = (StringValue, tokens::Colon, V);
+/// 
+/// 		#[derive(Parsable, Clone)]
+/// 		#[tag("type")]
+/// 		enum Object {
+/// 			#[variant("circle")]
+/// 			Circle(Field<StringValue>, tokens::Comma, Field<Number>),
+/// 			#[variant("rect")]
+/// 			Rect(Field<StringValue>, tokens::Comma, Field<Number>, tokens::Comma, Field<Number>)
+/// 		}
+/// 
+/// 		let mut buffer = CharStream::new("{\"type\": \"circle\", \"radius\": 5}".to_owned()).build();
+/// 		assert!(matches!(Group::<tokens::Brace, Object>::parse(&mut buffer).unwrap().item(), Object::Circle(..)));
+/// 
+/// 		let mut buffer = CharStream::new("{\"type\": \"rect\", \"width\": 1, \"height\": 2}".to_owned()).build();
+/// 		assert!(matches!(Group::<tokens::Brace, Object>::parse(&mut buffer).unwrap().item(), Object::Rect(..)));
+/// 
+/// 		let mut buffer = CharStream::new("{\"type\": \"line\"}".to_owned()).build();
+/// 		let error = Group::<tokens::Brace, Object>::parse(&mut buffer).err().unwrap();
+/// 		assert_eq!(format!("{:?}", error), "0:9:Error: 'Unknown type 'line', expected one of: \"circle\", \"rect\".'");
+/// 	}
+/// # }
+/// ```
+#[derive(Clone)]
+pub enum Tag {
+	Identifier(Identifier),
+	String(StringValue)
+}
+
+impl Parse for Tag {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut tag_value = value.clone();
+		match tag_value.next() {
+			Some('"') => Ok(Self::String(StringValue::parse(value)?)),
+			_ => Ok(Self::Identifier(Identifier::parse(value)?))
+		}
+    }
+
+    fn span(&self) -> Span {
+        match self {
+			Self::Identifier(identifier) => identifier.span(),
+			Self::String(string) => string.span()
+		}
+    }
+}
+
+impl Tag {
+	/// Reads the tag that decides what follows, without consuming anything.
+	/// When the input starts with a key named `name` and a colon, like `"type": "circle"`, the tag is the value after the colon,
+	/// otherwise it is the first identifier or string.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Key, Tag};
+	/// # fn main() {
+	/// 	let buffer = CharStream::new("\"type\": \"circle\", \"radius\": 5".to_owned()).build();
+	/// 	assert_eq!(Tag::read(&buffer, "type").unwrap().key(), "circle");
+	/// 	assert_eq!(buffer.position().index(), 0);
+	/// 
+	/// 	let buffer = CharStream::new("circle, 5".to_owned()).build();
+	/// 	assert_eq!(Tag::read(&buffer, "type").unwrap().key(), "circle");
+	/// # }
+	/// ```
+	pub fn read(value: &CharStream, name: &str) -> Result<Self, ParseError> {
+		let mut tag_value = value.clone();
+		let tag = Self::parse(&mut tag_value)?;
+		if tag.key() != name || tokens::Colon::parse(&mut tag_value).is_err() {
+			return Ok(tag);
+		}
+
+		Self::parse(&mut tag_value)
+	}
+}
+
+impl Key for Tag {
+	fn key(&self) -> &str {
+		match self {
+			Self::Identifier(identifier) => identifier.key(),
+			Self::String(string) => string.key()
+		}
+	}
+}
+
+impl fmt::Debug for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Identifier(identifier) => write!(f, "Tag({:?})", identifier),
+			Self::String(string) => write!(f, "Tag({:?})", string)
+		}
+    }
+}
+
+/// Entry is a value with a key, like a field in an object.
+/// A [`List`] of entries can be queried with [`List::get`].
+pub trait Entry {
+	type Value;
+
+	fn key(&self) -> &str;
+	fn value(&self) -> &Self::Value;
+}
+
+impl<K, S, V> Entry for (K, S, V) where
+	K: Key,
+	S: tokens::Token
+{
+	type Value = V;
+
+	fn key(&self) -> &str {
+		self.0.key()
+	}
+
+	fn value(&self) -> &Self::Value {
+		&self.2
+	}
+}
+
+impl<I, S, O> Parse for List<I, S, O> where
+	I: Parse,
+	S: tokens::Token,
+	O: ListOptions
+{
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        let mut items = Vec::new();
+		let start = value.position();
+
+		loop {
+			if let Some(max) = O::MAX_ITEMS.filter(|max| items.len() >= *max) {
+				Self::check_excess(value, max, false)?;
+				break;
+			}
+
+			let mut item_value = value.clone();
+			let item = match I::parse(&mut item_value) {
+				Ok(item) => {
+					value.goto(item_value.position())?;
+					item
+				}
+				Err(error) => {
+					if (!items.is_empty() && !O::TERMINATED) || error.is_committed() {
+						return Err(error);
+					}
+					value.record_failure(&error);
+					break
+				}
+			};
+
+			let terminator = match O::TERMINATED {
+				true => match S::parse(value) {
+					Ok(terminator) => Some(terminator),
+					Err(error) => {
+						let cause = "Expected every item of the list to end with a separator.";
+						return Err(match error.kind() {
+							ParseErrorKind::UnexpectedEof => ParseError::eof(cause, error.position().clone()),
+							ParseErrorKind::Mismatch => ParseError::new(cause, error.position().clone())
+						});
+					}
+				},
+				false => None
+			};
+
+			if O::SINGLE_LINE && value.newline_ahead() {
+				items.push((item, terminator));
+				break;
+			}
+
+			if let Some(max) = O::MAX_ITEMS.filter(|max| items.len() + 1 >= *max) {
+				Self::check_excess(value, max, !O::TERMINATED)?;
+				items.push((item, terminator));
+				break;
+			}
+
+			if O::TERMINATED {
+				items.push((item, terminator));
+				continue;
+			}
+
+			let separator = match S::parse(value) {
+				Ok(separator) if O::SINGLE_LINE && value.newline_ahead() => {
+					return Err(ParseError::new("Expected the next item of the list on the same line as the separator.", separator.span().start));
+				}
+				Ok(separator) => Some(separator),
+				Err(error) if O::SOFT_SEPARATORS && I::parse(&mut value.clone()).is_ok() => {
+					value.record_failure(&error);
+					None
+				}
+				Err(error) => {
+					value.record_failure(&error);
+					items.push((item, None));
+					break;
 				}
 			};
 
-			let separator = match S::parse(value) {
-				Ok(value) => Some(value),
-				_ => {
-					items.push((item, None));
-					break;
-				}
-			};
+			items.push((item, separator));
+		}
+
+		let end = value.position();
+
+		Ok(Self { items, span: Span::new(start, end), options: PhantomData })
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<I, S, O> fmt::Debug for List<I, S, O> where 
+	I: Parse + fmt::Debug,
+	S: tokens::Token + fmt::Debug,
+	O: ListOptions
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "List({:#?}, from {})", self.items, self.span())
+    }
+}
+
+/// StringValue represents a string.
+/// this is necessary because it needs to store some additional information for the AST, like the info necessary for [`Parse::span`].
+/// 
+/// Escape sequences are decoded: `\"`, `\\`, `\/`, `\n`, `\t`, `\r`, `\0` and `\uXXXX` (four hex digits).
+/// Any other escape is an error.
+/// ```
+/// # use parseal::parsing::{StringValue, Parse, charstream::CharStream};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("\"Hello, world!\"".to_owned()).build();
+/// 	let value = StringValue::parse(&mut buffer);
+/// 
+/// 	assert!(value.is_ok());
+/// 
+/// 	let mut buffer = CharStream::new("\"say \\\"hi\\\"\\n\\u0041\"".to_owned()).build();
+/// 	let value = StringValue::parse(&mut buffer).unwrap();
+/// 
+/// 	assert_eq!(value.value(), "say \"hi\"\nA");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct StringValue {
+	span: Span,
+	value: String
+}
+
+impl StringValue {
+	/// Creates a string that was not parsed, with the given (already decoded) content and span.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, Number, Parse, StringValue};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("name = 12".to_owned()).build();
+	/// 	let (name, _, number) = <(Identifier, tokens::Equal, Number)>::parse(&mut buffer).unwrap();
+	/// 
+	/// 	let renamed = Identifier::new("title", name.span());
+	/// 	assert!(renamed == "title");
+	/// 	assert_eq!(renamed.span(), name.span());
+	/// 
+	/// 	let doubled = Number::new("24", number.span());
+	/// 	assert_eq!(format!("{:?}", doubled), format!("Number(24, from {})", number.span()));
+	/// 
+	/// 	let string = StringValue::new("title", name.span());
+	/// 	assert_eq!(string.value(), "title");
+	/// 	assert_eq!(string.span().end.column(), 4);
+	/// # }
+	/// ```
+	pub fn new(value: &str, span: Span) -> Self {
+		Self { span, value: value.to_string() }
+	}
+
+	/// Returns the content of the string, with escape sequences decoded.
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	fn escape(value: &mut CharStream) -> Result<Option<char>, ParseError> {
+		let start = value.position();
+		let chr = match value.next() {
+			Some('"') => '"',
+			Some('\\') => '\\',
+			Some('/') => '/',
+			Some('n') => '\n',
+			Some('t') => '\t',
+			Some('r') => '\r',
+			Some('0') => '\0',
+			Some('u') => {
+				let mut code = String::new();
+				for _ in 0..4 {
+					match value.next() {
+						Some(chr) if chr.is_ascii_hexdigit() => code.push(chr),
+						_ => return Err(ParseError::new("Expected 4 hex digits in unicode escape.", start))
+					}
+				}
+
+				match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+					Some(chr) => chr,
+					None => return Err(ParseError::new("Unicode escape is not a valid character.", start))
+				}
+			}
+			Some(chr) => return Err(ParseError::new(&format!("Unknown escape sequence '\\{}'.", chr), start)),
+			None => return Ok(None)
+		};
+
+		Ok(Some(chr))
+	}
+}
+
+impl Parse for StringValue {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let left = <tokens::Quote as tokens::Delimiter>::Start::parse(value)?;
+		let mut inner_value = String::new();
+
+		let mut string_value = value.clone();
+
+		let mut position = string_value.position();
+
+		string_value.set_whitespace(WhitespaceType::KeepAll);
+		loop {
+			match string_value.next() {
+				Some('\\') => match Self::escape(&mut string_value)? {
+					Some(chr) => {
+						inner_value.push(chr);
+						position = string_value.position();
+					}
+					None => break
+				}
+				Some(value) if value != '"' => {
+					inner_value.push(value);
+					position = string_value.position();
+				}
+				_ => break
+			}
+		}
+
+		value.goto(position)?;
+		
+		let right = <tokens::Quote as tokens::Delimiter>::End::parse(value)?;
+
+		Ok(Self { span: Span::new(left.span().start, right.span().end), value: inner_value})
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for StringValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "StringValue({}, from {})", self.value, self.span())
+    }
+}
+
+/// A FoldedString is a [`StringValue`] with its whitespace folded, like an HTML attribute or a folded YAML scalar.
+/// 
+/// After escape sequences are decoded, leading and trailing whitespace is removed, 
+/// and every run of whitespace inside the string is replaced by a single space.
+/// Whitespace is every character for which [`char::is_whitespace`] is true, like spaces, tabs and newlines, 
+/// including those written as an escape sequence like `\n`.
+/// A [`StringValue`] keeps its content as it is.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, FoldedString, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("\"a   b\n c\"".to_owned()).build();
+/// 	let value = FoldedString::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.value(), "a b c");
+/// 
+/// 	let mut buffer = CharStream::new("\"\\t padded \\n\"".to_owned()).build();
+/// 	let value = FoldedString::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.value(), "padded");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FoldedString {
+	span: Span,
+	value: String
+}
+
+impl FoldedString {
+	/// Returns the folded content of the string.
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+}
+
+impl Parse for FoldedString {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let string = StringValue::parse(value)?;
+		let folded = string.value.split_whitespace().collect::<Vec<_>>().join(" ");
+
+		Ok(Self { span: string.span, value: folded })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for FoldedString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "FoldedString({}, from {})", self.value, self.span)
+    }
+}
+
+/// An Identifier represents things like words and names.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse, tokens, self};
+/// 
+/// # fn main() {
+/// 	let buffer = "hello world".to_owned();
+/// 	let mut buffer = CharStream::new(buffer).build();
+/// 	
+/// 	let value = Vec::<Identifier>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.len(), 2);
+/// 
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::Parsable;
+/// 		#[derive(Parsable, Clone)]
+/// 		enum Bool {
+/// 			True(#[value("true")] Identifier),
+/// 			False(#[value("false")] Identifier)
+/// 		}
+/// 
+/// 		let mut buffer = CharStream::new("true | false".to_owned()).build();
+/// 		let value = <(Bool, tokens::Pipe, Bool)>::parse(&mut buffer);
+/// 		assert!(value.is_ok());
+/// 	}
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Identifier {
+	identifier: String,
+	raw: bool,
+	span: Span
+}
+
+impl Identifier {
+	/// Creates an identifier that was not parsed, with the given text and span.
+	pub fn new(identifier: &str, span: Span) -> Self {
+		Self { identifier: identifier.to_string(), raw: false, span }
+	}
+
+	/// Returns true if the identifier was written with the `r#` prefix, like `r#match`.
+	/// The prefix is not part of the identifier itself, but it is part of its span.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("r#match".to_owned()).build();
+	/// 	let value = Identifier::parse(&mut buffer).unwrap();
+	/// 	assert!(value == "match");
+	/// 	assert!(value.is_raw());
+	/// 	assert_eq!(value.span().end.column(), 7);
+	/// 
+	/// 	let mut buffer = CharStream::new("r # match".to_owned()).build();
+	/// 	let value = Identifier::parse(&mut buffer).unwrap();
+	/// 	assert!(value == "r");
+	/// 	assert!(!value.is_raw());
+	/// # }
+	/// ```
+	pub fn is_raw(&self) -> bool {
+		self.raw
+	}
+}
+
+impl Parse for Identifier {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut identifier = String::new();
+
+		value.count_node()?;
+		let mut ident_value = value.clone();
+		ident_value.skip_whitespace();
+		let start = ident_value.position();
+
+		let mut raw_value = ident_value.clone();
+		raw_value.set_whitespace(WhitespaceType::KeepAll);
+		let raw = matches!((raw_value.next(), raw_value.next(), raw_value.clone().next()), 
+			(Some('r'), Some('#'), Some(chr)) if chr.is_alphabetic());
+		if raw {
+			ident_value = raw_value;
+		}
+
+		match ident_value.next() {
+			Some(chr) if chr.is_alphabetic() => {
+				let mut position = ident_value.position();
+				identifier.push(chr);
+
+				ident_value.set_whitespace(WhitespaceType::KeepAll);
+
+				loop {
+					match ident_value.next() {
+						Some(value) if value.is_alphanumeric() => {
+							identifier.push(value);
+							position = ident_value.position();
+						}
+						_ => break
+					}
+				}
+
+				value.goto(position)?;
+			}
+			_ => return Err(ParseError::new("Did not find identifier", start))
+		}
+
+		let end = value.position();
+
+		Ok(Self { identifier, raw, span: Span::new(start, end)})
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Identifier({}, from {})", self.identifier, self.span)
+    }
+}
+
+impl PartialEq<&str> for Identifier {
+    fn eq(&self, other: &&str) -> bool {
+        self.identifier == *other
+    }
+}
+
+/// A Number is a representation of a number, duh.
+/// this representation is needed since it needs to store some additional information for the AST.
+/// ```
+/// # use parseal::parsing::{Number, Parse, charstream::CharStream};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("69420".to_owned()).build();
+/// 	let value = Number::parse(&mut buffer);
+/// 
+/// 	assert!(value.is_ok());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Number {
+	value: String,
+	span: Span
+}
+
+impl Number {
+	/// Creates a number that was not parsed, with the given digits and span.
+	pub fn new(value: &str, span: Span) -> Self {
+		Self { value: value.to_string(), span }
+	}
+}
+
+impl Parse for Number {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut number = String::new();
+
+		value.count_node()?;
+		let mut num_value = value.clone();
+		num_value.skip_whitespace();
+		let start = num_value.position();
+
+		match num_value.next() {
+			Some(chr) if chr.is_numeric() => {
+				let mut position = num_value.position();
+				number.push(chr);
+
+				num_value.set_whitespace(WhitespaceType::KeepAll);
+
+				loop {
+					match num_value.next() {
+						Some(value) if value.is_numeric() => {
+							number.push(value);
+							position = num_value.position();
+						}
+						_ => break
+					}
+				}
+
+				value.goto(position)?;
+			}
+			_ => return Err(ParseError::new("Did not find number", start))
+		}
+
+		let end = value.position();
+
+		Ok(Number { value: number, span: Span::new(start, end)})
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Number({}, from {})", self.value, self.span)
+    }
+}
+
+/// A Word is the longest run of letters, digits and `_`, like `3d6` or `x_1`.
+/// Unlike an [`Identifier`] or a [`Number`], it can start with either a letter or a digit, 
+/// so it can be classified after it was parsed.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Number, Parse, Word};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("3d6".to_owned()).build();
+/// 	let word = Word::parse(&mut buffer).unwrap();
+/// 	assert!(word == "3d6");
+/// 	assert_eq!(word.span().end.column(), 3);
+/// 
+/// 	let mut buffer = CharStream::new("3d6".to_owned()).build();
+/// 	let _ = Number::parse(&mut buffer).unwrap();
+/// 	assert!(Word::parse(&mut buffer).unwrap() == "d6");
+/// 
+/// 	let mut buffer = CharStream::new("-1".to_owned()).build();
+/// 	assert!(Word::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Word {
+	word: String,
+	span: Span
+}
+
+impl Word {
+	pub fn value(&self) -> &str {
+		&self.word
+	}
+}
+
+impl Parse for Word {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut word = String::new();
+
+		value.count_node()?;
+		let mut word_value = value.clone();
+		word_value.skip_whitespace();
+		let start = word_value.position();
+
+		match word_value.next() {
+			Some(chr) if chr.is_alphanumeric() || chr == '_' => {
+				let mut position = word_value.position();
+				word.push(chr);
+
+				word_value.set_whitespace(WhitespaceType::KeepAll);
+
+				loop {
+					match word_value.next() {
+						Some(value) if value.is_alphanumeric() || value == '_' => {
+							word.push(value);
+							position = word_value.position();
+						}
+						_ => break
+					}
+				}
+
+				value.goto(position)?;
+			}
+			_ => return Err(ParseError::new("Did not find word", start))
+		}
+
+		let end = value.position();
+
+		Ok(Self { word, span: Span::new(start, end) })
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Word({}, from {})", self.word, self.span)
+    }
+}
+
+impl PartialEq<&str> for Word {
+    fn eq(&self, other: &&str) -> bool {
+        self.word == *other
+    }
+}
+
+/// Keywords is a closed set of words, that each stand for a value of `Self`.
+/// It is parsed with [`Keyword`], and can be derived for an enum without fields using `#[derive(ParsableKeyword)]`.
+pub trait Keywords: Clone {
+	/// All words that are part of the set.
+	const KEYWORDS: &'static [&'static str];
+
+	fn from_keyword(keyword: &str) -> Option<Self> where Self: Sized;
+}
+
+/// A Keyword is a [`Word`] that is one of the [`Keywords`] of `K`.
+/// 
+/// With the `derive` feature, every variant of an enum matches its own name, or the value given with `#[value("...")]`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Keyword, Parse};
+/// # fn main() {
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{ParsableKeyword, parsing};
+/// 		#[derive(ParsableKeyword, Clone, Debug, PartialEq)]
+/// 		enum Level {
+/// 			#[value("DEBUG")]
+/// 			Debug,
+/// 			#[value("INFO")]
+/// 			Info,
+/// 			#[value("WARN")]
+/// 			Warn
+/// 		}
+/// 
+/// 		let mut buffer = CharStream::new("WARN".to_owned()).build();
+/// 		let level = Keyword::<Level>::parse(&mut buffer).unwrap();
+/// 		assert_eq!(*level.value(), Level::Warn);
+/// 
+/// 		let mut buffer = CharStream::new("ERROR".to_owned()).build();
+/// 		let error = Keyword::<Level>::parse(&mut buffer).unwrap_err();
+/// 		assert_eq!(format!("{:?}", error), "0:0:Error: 'Unknown keyword 'ERROR', expected one of: DEBUG, INFO, WARN.'");
+/// 	}
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Keyword<K> where K: Keywords {
+	value: K,
+	span: Span
+}
+
+impl<K> Keyword<K> where K: Keywords {
+	pub fn value(&self) -> &K {
+		&self.value
+	}
+}
+
+impl<K> Parse for Keyword<K> where K: Keywords {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut keyword_value = value.clone();
+		let word = Word::parse(&mut keyword_value)?;
+
+		match K::from_keyword(word.value()) {
+			Some(keyword) => {
+				value.goto(keyword_value.position())?;
+				Ok(Self { value: keyword, span: word.span() })
+			}
+			None => Err(ParseError::new(
+				&format!("Unknown keyword '{}', expected one of: {}.", word.value(), K::KEYWORDS.join(", ")),
+				word.span().start
+			))
+		}
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<K> fmt::Debug for Keyword<K> where K: Keywords + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Keyword({:?}, from {})", self.value, self.span)
+    }
+}
+
+/// Words is a set of words that can be parsed with [`OneOf`].
+pub trait Words: Clone {
+	const WORDS: &'static [&'static str];
+}
+
+/// OneOf is a [`Word`] that is one of the [`Words`] of `W`, it keeps which of the words matched.
+/// Because the whole word is parsed first, `postal` does not match `post`.
+/// 
+/// Unlike a [`Keyword`], it does not need a type for the result, only a type that lists the words.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, OneOf, Parse, Words};
+/// # fn main() {
+/// 	#[derive(Clone)]
+/// 	struct Method;
+/// 
+/// 	impl Words for Method {
+/// 		const WORDS: &'static [&'static str] = &["get", "post", "put"];
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("post /users".to_owned()).build();
+/// 	let method = OneOf::<Method>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(method.word(), "post");
+/// 	assert_eq!(method.span().end.column(), 4);
+/// 
+/// 	let mut buffer = CharStream::new("postal".to_owned()).build();
+/// 	let error = OneOf::<Method>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'Unknown word 'postal', expected one of: get, post, put.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OneOf<W> where W: Words {
+	word: &'static str,
+	span: Span,
+	words: PhantomData<W>
+}
+
+impl<W> OneOf<W> where W: Words {
+	/// Returns the word that matched.
+	pub fn word(&self) -> &'static str {
+		self.word
+	}
+}
+
+impl<W> Parse for OneOf<W> where W: Words {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut word_value = value.clone();
+		let word = Word::parse(&mut word_value)?;
+
+		match W::WORDS.iter().find(|&&option| word == option) {
+			Some(option) => {
+				value.goto(word_value.position())?;
+				Ok(Self { word: option, span: word.span(), words: PhantomData })
+			}
+			None => Err(ParseError::new(
+				&format!("Unknown word '{}', expected one of: {}.", word.value(), W::WORDS.join(", ")),
+				word.span().start
+			))
+		}
+    }
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<W> fmt::Debug for OneOf<W> where W: Words {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OneOf({}, from {})", self.word, self.span)
+    }
+}
+
+/// Terminators is a set of characters that end an unquoted value, like a [`Scalar`].
+pub trait Terminators: Clone {
+	const TERMINATORS: &'static [char];
+}
+
+/// Ends a value at the end of the line.
+#[derive(Debug, Clone)]
+pub struct LineEnd;
+
+impl Terminators for LineEnd {
+	const TERMINATORS: &'static [char] = &['\n'];
+}
+
+/// Ends a value at a comma, or at the end of the line.
+#[derive(Debug, Clone)]
+pub struct CommaOrLineEnd;
+
+impl Terminators for CommaOrLineEnd {
+	const TERMINATORS: &'static [char] = &[',', '\n'];
+}
+
+/// A Scalar is a string that is either quoted, like `"a b"`, or bare, like `abc`.
+/// 
+/// A quoted scalar is parsed as a [`StringValue`], so escape sequences are decoded.
+/// A bare scalar runs until one of the characters of `T` (see [`Terminators`]) or the end of the input, 
+/// the terminator is not consumed and whitespace around the value is trimmed.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, CommaOrLineEnd, LineEnd, List, Parse, Scalar};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("  hello world  \nnext".to_owned()).build();
+/// 	let value = Scalar::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.value(), "hello world");
+/// 	assert!(!value.is_quoted());
+/// 
+/// 	let mut buffer = CharStream::new("\"a \\\"b\\\"\", c d".to_owned()).build();
+/// 	let value = List::<Scalar<CommaOrLineEnd>, tokens::Comma>::parse(&mut buffer).unwrap();
+/// 	let values = value.items().map(|item| item.value()).collect::<Vec<_>>();
+/// 	assert_eq!(values, vec!["a \"b\"", "c d"]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Scalar<T = LineEnd> where T: Terminators {
+	value: String,
+	quoted: bool,
+	span: Span,
+	terminators: PhantomData<T>
+}
+
+impl<T> Scalar<T> where T: Terminators {
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	pub fn is_quoted(&self) -> bool {
+		self.quoted
+	}
+}
+
+impl<T> Parse for Scalar<T> where T: Terminators {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut scalar_value = value.clone();
+		scalar_value.skip_whitespace();
+		let start = scalar_value.position();
+
+		if let Some('"') = scalar_value.next() {
+			let string = StringValue::parse(value)?;
+			return Ok(Self { value: string.value, quoted: true, span: string.span, terminators: PhantomData });
+		}
+
+		value.count_node()?;
+		let mut scalar_value = value.clone();
+		scalar_value.goto(start.clone())?;
+		scalar_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut scalar = String::new();
+		let mut end = start.clone();
+		loop {
+			let mut next_value = scalar_value.clone();
+			match next_value.next() {
+				Some(chr) if !T::TERMINATORS.contains(&chr) => {
+					scalar.push(chr);
+					if !chr.is_whitespace() {
+						end = next_value.position();
+					}
+					scalar_value = next_value;
+				}
+				_ => break
+			}
+		}
+
+		let scalar = scalar.trim_end().to_string();
+		if scalar.is_empty() {
+			return Err(ParseError::new("Did not find scalar.", start));
+		}
+
+		value.goto(end.clone())?;
+		Ok(Self { value: scalar, quoted: false, span: Span::new(start, end), terminators: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<T> fmt::Debug for Scalar<T> where T: Terminators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Scalar({}, from {})", self.value, self.span)
+    }
+}
+
+/// A Shebang is a first line like `#!/usr/bin/env foo`, that names the interpreter of a script.
+/// 
+/// It only matches at the very start of the input, nothing is skipped before it, so a `#!` anywhere else is an error.
+/// The text after `#!` is kept verbatim up to the end of the line, the newline itself is not consumed.
+/// When the stream skips `#` comments, the shebang has to be parsed before anything else, or it will be skipped as a comment.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse, Shebang};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("#!/usr/bin/env foo -x\nhello".to_owned()).build();
+/// 	let shebang = Shebang::parse(&mut buffer).unwrap();
+/// 	assert_eq!(shebang.value(), "/usr/bin/env foo -x");
+/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "hello");
+/// 
+/// 	let mut buffer = CharStream::new("hello\n#!/bin/sh".to_owned()).build();
+/// 	let _ = Identifier::parse(&mut buffer).unwrap();
+/// 	let error = Shebang::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:5:Error: 'A shebang can only be at the start of the input.'");
+/// 
+/// 	let mut buffer = CharStream::new("# comment".to_owned()).build();
+/// 	assert!(Shebang::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Shebang {
+	value: String,
+	span: Span
+}
+
+impl Shebang {
+	/// Returns the text after the `#!`.
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+}
+
+impl Parse for Shebang {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let start = value.position();
+		if start.index() != 0 {
+			return Err(ParseError::new("A shebang can only be at the start of the input.", start));
+		}
+
+		let mut shebang_value = value.clone();
+		shebang_value.set_whitespace(WhitespaceType::KeepAll);
+		if shebang_value.next() != Some('#') || shebang_value.next() != Some('!') {
+			return Err(ParseError::new("Expected a shebang to start with '#!'.", start));
+		}
+		value.count_node()?;
+
+		let mut text = String::new();
+		loop {
+			let mut next_value = shebang_value.clone();
+			match next_value.next() {
+				Some(chr) if chr != '\n' => {
+					text.push(chr);
+					shebang_value = next_value;
+				}
+				_ => break
+			}
+		}
+
+		value.goto(shebang_value.position())?;
+		let end = value.position();
+		Ok(Self { value: text.trim_end_matches('\r').to_owned(), span: Span::new(start, end) })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl fmt::Debug for Shebang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Shebang({:?}, from {})", self.value, self.span)
+    }
+}
+
+/// A Heredoc is a block of lines that ends at a line with only its delimiter, like `<<EOF` followed by lines and then `EOF`.
+/// 
+/// The delimiter is a run of letters, digits and `_`, and has to be followed by the end of the line.
+/// The lines of the body are kept verbatim, every line ends with a newline.
+/// With `<<-`, the line that ends the heredoc can be indented.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Heredoc, Identifier, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("<<EOF\n  first line\nsecond line\nEOF\nnext".to_owned()).build();
+/// 	let heredoc = Heredoc::parse(&mut buffer).unwrap();
+/// 	assert_eq!(heredoc.delimiter(), "EOF");
+/// 	assert_eq!(heredoc.body(), "  first line\nsecond line\n");
+/// 	assert_eq!(heredoc.span().end.row(), 3);
+/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "next");
+/// 
+/// 	let mut buffer = CharStream::new("<<-END\nbody\n    END".to_owned()).build();
+/// 	let heredoc = Heredoc::parse(&mut buffer).unwrap();
+/// 	assert!(heredoc.is_indented());
+/// 	assert_eq!(heredoc.body(), "body\n");
+/// 
+/// 	let mut buffer = CharStream::new("<<EOF\nbody\n  EOF".to_owned()).build();
+/// 	let error = Heredoc::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "2:5:Error: 'Unexpected end of input, expected a line with 'EOF' to end the heredoc.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Heredoc {
+	delimiter: String,
+	body: String,
+	indented: bool,
+	span: Span
+}
+
+impl Heredoc {
+	pub fn delimiter(&self) -> &str {
+		&self.delimiter
+	}
+
+	pub fn body(&self) -> &str {
+		&self.body
+	}
+
+	/// Returns true when the heredoc started with `<<-`, so its last line could be indented.
+	pub fn is_indented(&self) -> bool {
+		self.indented
+	}
+
+	/// Reads the rest of the current line, and moves past its newline.
+	/// Returns the line with the position of its end, before the newline.
+	fn line(value: &mut CharStream) -> Option<(String, Position)> {
+		let mut line = String::new();
+		loop {
+			let end = value.position();
+			match value.next() {
+				Some('\n') => return Some((line, end)),
+				Some(chr) => line.push(chr),
+				None if line.is_empty() => return None,
+				None => return Some((line, end))
+			}
+		}
+	}
+}
+
+impl Parse for Heredoc {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut heredoc_value = value.clone();
+		heredoc_value.skip_whitespace();
+		let start = heredoc_value.position();
+		heredoc_value.set_whitespace(WhitespaceType::KeepAll);
+
+		if heredoc_value.next() != Some('<') || heredoc_value.next() != Some('<') {
+			return Err(ParseError::new("Expected a heredoc to start with '<<'.", start));
+		}
+
+		let mut dash_value = heredoc_value.clone();
+		let indented = dash_value.next() == Some('-');
+		if indented {
+			heredoc_value = dash_value;
+		}
+
+		let mut delimiter = String::new();
+		loop {
+			let mut next_value = heredoc_value.clone();
+			match next_value.next() {
+				Some(chr) if chr.is_alphanumeric() || chr == '_' => {
+					delimiter.push(chr);
+					heredoc_value = next_value;
+				}
+				_ => break
+			}
+		}
+		if delimiter.is_empty() {
+			return Err(ParseError::new("Expected a delimiter after '<<'.", heredoc_value.position()));
+		}
+
+		let rest_position = heredoc_value.position();
+		match Heredoc::line(&mut heredoc_value) {
+			Some((rest, _)) if rest.trim().is_empty() => {}
+			_ => return Err(ParseError::new("Expected the heredoc delimiter to end the line.", rest_position))
+		}
+
+		let mut body = String::new();
+		let end = loop {
+			let (line, end) = match Heredoc::line(&mut heredoc_value) {
+				Some(line) => line,
+				None => return Err(ParseError::eof(
+					&format!("Unexpected end of input, expected a line with '{}' to end the heredoc.", delimiter), 
+					heredoc_value.position()
+				))
+			};
+
+			let terminator = line.trim_end_matches('\r');
+			let terminator = match indented {
+				true => terminator.trim_start(),
+				false => terminator
+			};
+			if terminator == delimiter {
+				break end;
+			}
+
+			body.push_str(&line);
+			body.push('\n');
+		};
+
+		value.goto(end.clone())?;
+		Ok(Self { delimiter, body, indented, span: Span::new(start, end) })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl fmt::Debug for Heredoc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Heredoc({}, {:?}, from {})", self.delimiter, self.body, self.span)
+    }
+}
+
+/// Until is the raw text up to one of the characters of `T` (see [`Terminators`]) or the end of the input.
+/// Nothing is skipped or trimmed, whitespace is part of the text, and the terminator is not consumed.
+/// The text can not be empty.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Parse, Terminators, Until};
+/// # fn main() {
+/// 	#[derive(Clone)]
+/// 	struct PathEnd;
+/// 
+/// 	impl Terminators for PathEnd {
+/// 		const TERMINATORS: &'static [char] = &['/', '?'];
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("search results?q=parseal".to_owned()).build();
+/// 	let segment = Until::<PathEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(segment.value(), "search results");
+/// 	assert_eq!(segment.span().end.column(), 14);
+/// 	assert!(tokens::Punct::<'?'>::parse(&mut buffer).is_ok());
+/// 
+/// 	let mut buffer = CharStream::new("?q".to_owned()).build();
+/// 	assert!(Until::<PathEnd>::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Until<T> where T: Terminators {
+	value: String,
+	span: Span,
+	terminators: PhantomData<T>
+}
+
+impl<T> Until<T> where T: Terminators {
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+}
+
+impl<T> Parse for Until<T> where T: Terminators {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let start = value.position();
+
+		let mut until_value = value.clone();
+		until_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut text = String::new();
+		loop {
+			let mut next_value = until_value.clone();
+			match next_value.next() {
+				Some(chr) if !T::TERMINATORS.contains(&chr) => {
+					text.push(chr);
+					until_value = next_value;
+				}
+				_ => break
+			}
+		}
+
+		if text.is_empty() {
+			return Err(ParseError::new("Did not find any text before a terminator.", start));
+		}
+
+		value.goto(until_value.position())?;
+		let end = value.position();
+		Ok(Self { value: text, span: Span::new(start, end), terminators: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<T> fmt::Debug for Until<T> where T: Terminators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Until({:?}, from {})", self.value, self.span)
+    }
+}
+
+/// Columns sets the layout of a [`FixedWidth`] row.
+pub trait Columns: Clone {
+	/// The width of every column, in characters.
+	const WIDTHS: &'static [usize];
+	/// What happens with text after the last column.
+	const OVERFLOW: Overflow = Overflow::Error;
+}
+
+/// What a [`FixedWidth`] row does with a line that is longer than all of its columns together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Overflow {
+	/// The row returns an error at the first character after the last column.
+	Error,
+	/// The text after the last column is kept, see [`FixedWidth::overflow`].
+	Capture
+}
+
+/// A FixedWidth row is a line that is split into columns with a fixed width, like the rows of a report.
+/// 
+/// Every cell is trimmed, and a line that is shorter than the columns has empty cells at the end.
+/// The newline at the end of the row is consumed, so a table is a `Vec` of rows.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Columns, FixedWidth, Overflow, Parse};
+/// # fn main() {
+/// 	#[derive(Clone)]
+/// 	struct Report;
+/// 
+/// 	impl Columns for Report {
+/// 		const WIDTHS: &'static [usize] = &[8, 6];
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("name    score\nalice   12\nbob\n".to_owned()).build();
+/// 	let rows = Vec::<FixedWidth<Report>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(rows.len(), 3);
+/// 	assert_eq!(rows[1].cells(), ["alice", "12"]);
+/// 	assert_eq!(rows[2].cells(), ["bob", ""]);
+/// 
+/// 	let mut buffer = CharStream::new("alice   12    extra".to_owned()).build();
+/// 	let error = FixedWidth::<Report>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:14:Error: 'Expected the row to end after 2 columns.'");
+/// 
+/// 	#[derive(Clone)]
+/// 	struct Lenient;
+/// 
+/// 	impl Columns for Lenient {
+/// 		const WIDTHS: &'static [usize] = &[8, 6];
+/// 		const OVERFLOW: Overflow = Overflow::Capture;
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("alice   12    extra".to_owned()).build();
+/// 	let row = FixedWidth::<Lenient>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(row.overflow(), Some("extra"));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FixedWidth<C> where C: Columns {
+	cells: Vec<String>,
+	overflow: Option<String>,
+	span: Span,
+	columns: PhantomData<C>
+}
+
+impl<C> FixedWidth<C> where C: Columns {
+	pub fn cells(&self) -> &[String] {
+		&self.cells
+	}
+
+	/// Returns the trimmed text after the last column, when it is captured and not empty.
+	pub fn overflow(&self) -> Option<&str> {
+		self.overflow.as_deref()
+	}
+}
+
+impl<C> Parse for FixedWidth<C> where C: Columns {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let start = value.position();
+		if value.is_at_end() {
+			return Err(ParseError::eof("Unexpected end of input, expected a row.", start));
+		}
+
+		let mut row_value = value.clone();
+		row_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut line = Vec::new();
+		let mut overflow_start = None;
+		loop {
+			if line.len() == C::WIDTHS.iter().sum::<usize>() && overflow_start.is_none() {
+				overflow_start = Some(row_value.position());
+			}
+
+			match row_value.next() {
+				Some('\n') | None => break,
+				Some(chr) => line.push(chr)
+			}
+		}
+		let end = row_value.position();
+
+		let mut rest = line.as_slice();
+		let mut cells = Vec::new();
+		for width in C::WIDTHS {
+			let (cell, next) = rest.split_at((*width).min(rest.len()));
+			cells.push(cell.iter().collect::<String>().trim().to_owned());
+			rest = next;
+		}
+
+		let overflow = rest.iter().collect::<String>().trim().to_owned();
+		if !overflow.is_empty() && C::OVERFLOW == Overflow::Error {
+			let position = overflow_start.unwrap_or_else(|| end.clone());
+			return Err(ParseError::new(&format!("Expected the row to end after {} columns.", C::WIDTHS.len()), position));
+		}
+
+		value.goto(end.clone())?;
+		Ok(Self { cells, overflow: Some(overflow).filter(|overflow| !overflow.is_empty()), span: Span::new(start, end), columns: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<C> fmt::Debug for FixedWidth<C> where C: Columns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "FixedWidth({:?}, from {})", self.cells, self.span)
+    }
+}
+
+/// Skips whitespace, and comments that start with `#` and run until the end of the line.
+fn skip_hash_comments(value: &mut CharStream) -> Result<(), ParseError> {
+	loop {
+		value.skip_whitespace();
+
+		let mut comment_value = value.clone();
+		if comment_value.next() != Some('#') {
+			return Ok(());
+		}
+
+		comment_value.set_whitespace(WhitespaceType::KeepAll);
+		while !matches!(comment_value.clone().next(), Some('\n') | None) {
+			comment_value.next();
+		}
+		value.goto(comment_value.position())?;
+	}
+}
+
+/// A KeyValueLine is a single `KEY=VALUE` line, like the lines of a `.env` file.
+/// 
+/// The key is a [`Word`], and can be preceded by `export`. 
+/// The value is a [`Scalar`], so it is either quoted, or runs until the end of the line. 
+/// A key without a value, like `KEY=`, has an empty value.
+/// Blank lines and comments starting with `#` before the line are skipped.
+#[derive(Clone)]
+pub struct KeyValueLine {
+	key: Word,
+	value: Option<Scalar>,
+	exported: bool,
+	span: Span
+}
+
+impl KeyValueLine {
+	pub fn key(&self) -> &str {
+		self.key.value()
+	}
+
+	pub fn value(&self) -> &str {
+		self.value.as_ref().map_or("", |value| value.value())
+	}
+
+	/// Returns true if the line started with `export`.
+	pub fn is_exported(&self) -> bool {
+		self.exported
+	}
+}
+
+impl Parse for KeyValueLine {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut line_value = value.clone();
+		skip_hash_comments(&mut line_value)?;
+		let start = line_value.position();
+
+		let mut key = Word::parse(&mut line_value)?;
+		let mut exported = false;
+		if key == "export" && !line_value.newline_ahead() {
+			let mut export_value = line_value.clone();
+			if let Ok(exported_key) = Word::parse(&mut export_value) {
+				key = exported_key;
+				exported = true;
+				line_value = export_value;
+			}
+		}
+
+		let equal = tokens::Equal::parse(&mut line_value)?;
+
+		let mut end_value = line_value.clone();
+		end_value.skip_whitespace();
+		let scalar = match line_value.newline_ahead() || end_value.is_at_end() {
+			true => None,
+			false => Some(Scalar::<LineEnd>::parse(&mut line_value)?)
+		};
+
+		let end = match &scalar {
+			Some(scalar) => scalar.span().end,
+			None => equal.span().end
+		};
+
+		value.goto(line_value.position())?;
+		Ok(Self { key, value: scalar, exported, span: Span::new(start, end) })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl fmt::Debug for KeyValueLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "KeyValueLine({} = {:?}, from {})", self.key(), self.value(), self.span)
+    }
+}
+
+/// A KeyValueFile is a list of [`KeyValueLine`]s that runs until the end of the input, like a `.env` file.
+/// The lines are kept in order, when a key occurs more than once [`KeyValueFile::get`] returns the last value.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, KeyValueFile, Parse};
+/// # fn main() {
+/// 	let source = "# database settings\nexport HOST = localhost\n\nNAME=\"my app\" \nEMPTY=\n# PORT=80\nPORT=8080\n";
+/// 	let mut buffer = CharStream::new(source.to_owned()).build();
+/// 	let file = KeyValueFile::parse(&mut buffer).unwrap();
+/// 
+/// 	let keys = file.lines().map(|line| line.key()).collect::<Vec<_>>();
+/// 	assert_eq!(keys, vec!["HOST", "NAME", "EMPTY", "PORT"]);
+/// 	assert_eq!(file.get("HOST"), Some("localhost"));
+/// 	assert_eq!(file.get("NAME"), Some("my app"));
+/// 	assert_eq!(file.get("EMPTY"), Some(""));
+/// 	assert_eq!(file.get("PORT"), Some("8080"));
+/// 	assert!(file.lines().next().unwrap().is_exported());
+/// 
+/// 	let mut buffer = CharStream::new("KEY=1\nnot a line\n".to_owned()).build();
+/// 	assert!(KeyValueFile::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct KeyValueFile {
+	lines: Vec<KeyValueLine>,
+	span: Span
+}
+
+impl KeyValueFile {
+	pub fn lines(&self) -> impl Iterator<Item = &KeyValueLine> {
+		self.lines.iter()
+	}
+
+	/// Returns the value of the last line with this key.
+	pub fn get(&self, key: &str) -> Option<&str> {
+		self.lines.iter().rev().find(|line| line.key() == key).map(|line| line.value())
+	}
+}
+
+impl Parse for KeyValueFile {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut lines = Vec::new();
+		let start = value.position();
+
+		loop {
+			let mut rest_value = value.clone();
+			skip_hash_comments(&mut rest_value)?;
+			if rest_value.is_at_end() {
+				value.goto(rest_value.position())?;
+				break;
+			}
+
+			lines.push(KeyValueLine::parse(value)?);
+		}
+
+		let end = value.position();
+		Ok(Self { lines, span: Span::new(start, end) })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl fmt::Debug for KeyValueFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "KeyValueFile({:#?}, from {})", self.lines, self.span)
+    }
+}
+
+#[derive(Clone)]
+pub struct Indent<T> {
+	values: Vec<T>,
+	depth: u8
+}
+
+impl<T> Parse for Indent<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        let mut values = Vec::new();
+
+		let mut indent_value = value.clone();
+		indent_value.set_whitespace(WhitespaceType::Indent);
+		let mut position = indent_value.position();
+
+		let mut item = T::parse(&mut indent_value);
+		let depth= indent_value.indent();
+		while item.is_ok() {
+			position = indent_value.position();
+			values.push(item?);
+			item = T::parse(&mut indent_value);
+
+			if indent_value.indent() != depth {
+				break;
+			}
+		}
+
+		if values.is_empty() {
+			Err(ParseError::new("Could not find Indent block.", position))
+		} else {
+			Ok(Self { values, depth })
+		}
+    }
+
+    fn span(&self) -> Span {
+        Span::new(self.values.first().unwrap().span().start, self.values.last().unwrap().span().end)
+    }
+}
+
+impl<T> fmt::Debug for Indent<T> where T: fmt::Debug + Parse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Indent({:#?}, from {}, depth {})", self.values, self.span(), self.depth)
+    }
+}
+
+/// IndentLevels parses `T` repeatedly, and keeps the indentation of the line every item starts on.
+/// Unlike [`Indent`], items do not need to have the same indentation, so an outline can be rebuilt into a tree afterwards.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Identifier, IndentLevels, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("fruit\n  apple\n    green\n  pear".to_owned()).build();
+/// 	let outline = IndentLevels::<Identifier>::parse(&mut buffer).unwrap();
+/// 
+/// 	let levels = outline.levels().map(|(_, depth)| depth).collect::<Vec<_>>();
+/// 	assert_eq!(levels, vec![0, 2, 4, 2]);
+/// 	assert!(buffer.is_at_end());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct IndentLevels<T> where T: Parse {
+	items: Vec<(T, usize)>
+}
+
+impl<T> IndentLevels<T> where T: Parse {
+	/// Iterates over the items, together with the indentation of the line they start on.
+	pub fn levels(&self) -> impl Iterator<Item = (&T, usize)> {
+		self.items.iter().map(|(item, depth)| (item, *depth))
+	}
+}
+
+impl<T> Parse for IndentLevels<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut items = Vec::new();
+
+		let mut indent_value = value.clone();
+		indent_value.set_whitespace(WhitespaceType::Indent);
+
+		loop {
+			let mut item_value = indent_value.clone();
+			item_value.skip_whitespace();
+			let depth = item_value.indent() as usize;
+
+			match T::parse(&mut item_value) {
+				Ok(item) => {
+					items.push((item, depth));
+					indent_value = item_value;
+				}
+				Err(error) if error.is_committed() => return Err(error),
+				Err(error) => {
+					if items.is_empty() {
+						return Err(error);
+					}
+					value.record_failure(&error);
+					break;
+				}
+			}
+		}
+
+		value.goto(indent_value.position())?;
+		Ok(Self { items })
+    }
+
+    fn span(&self) -> Span {
+        Span::new(self.items.first().unwrap().0.span().start, self.items.last().unwrap().0.span().end)
+    }
+}
+
+impl<T> fmt::Debug for IndentLevels<T> where T: fmt::Debug + Parse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "IndentLevels({:#?}, from {})", self.items, self.span())
+    }
+}
+
+/// AttributeSigil is the text around an [`Attribute`], like `@` or `#[` and `]`.
+pub trait AttributeSigil: Clone {
+	/// The text before the name of the attribute.
+	const OPEN: &'static str;
+	/// The text after the arguments of the attribute, this is empty when nothing closes the attribute.
+	const CLOSE: &'static str;
+}
+
+/// The sigil of an annotation like `@Override` or `@Size(10)`.
+#[derive(Debug, Clone)]
+pub struct At;
+
+impl AttributeSigil for At {
+	const OPEN: &'static str = "@";
+	const CLOSE: &'static str = "";
+}
+
+/// The sigil of an attribute like `#[inline]` or `#[derive(Clone)]`.
+#[derive(Debug, Clone)]
+pub struct HashBracket;
+
+impl AttributeSigil for HashBracket {
+	const OPEN: &'static str = "#[";
+	const CLOSE: &'static str = "]";
+}
+
+/// An Attribute is an annotation with a name and optional arguments between parentheses, like `@Size(10)` or `#[derive(Clone)]`.
+/// The text around it is set by `S` (see [`AttributeSigil`]), and the arguments are an `A`, by default a list of identifiers.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, At, Attribute, HashBracket, Number, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("@Foo".to_owned()).build();
+/// 	let attribute = Attribute::<At>::parse(&mut buffer).unwrap();
+/// 	assert!(*attribute.name() == "Foo");
+/// 	assert!(attribute.args().is_none());
+/// 
+/// 	let mut buffer = CharStream::new("@Size(10)".to_owned()).build();
+/// 	let attribute = Attribute::<At, Number>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(attribute.args().unwrap().span().start.column(), 6);
+/// 
+/// 	let mut buffer = CharStream::new("#[derive(Clone, Debug)]".to_owned()).build();
+/// 	let attribute = Attribute::<HashBracket>::parse(&mut buffer).unwrap();
+/// 	assert!(*attribute.name() == "derive");
+/// 	assert_eq!(attribute.args().unwrap().items().count(), 2);
+/// 	assert_eq!(attribute.span().end.column(), 23);
+/// 
+/// 	let mut buffer = CharStream::new("#[derive(Clone)".to_owned()).build();
+/// 	let error = Attribute::<HashBracket>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:15:Error: 'Unexpected end of input, expected right side of: '#[]'.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Attribute<S, A = List<Identifier, tokens::Comma>> where S: AttributeSigil, A: Parse {
+	name: Identifier,
+	args: Option<Group<tokens::Paren, A>>,
+	span: Span,
+	sigil: PhantomData<S>
+}
+
+impl<S, A> Attribute<S, A> where S: AttributeSigil, A: Parse {
+	pub fn name(&self) -> &Identifier {
+		&self.name
+	}
+
+	/// Returns the arguments between the parentheses, if there were any.
+	pub fn args(&self) -> Option<&A> {
+		self.args.as_ref().map(Group::item)
+	}
+}
+
+impl<S, A> Parse for Attribute<S, A> where S: AttributeSigil, A: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let pair = format!("{}{}", S::OPEN, S::CLOSE);
+		let start = tokens::parse_fence(value, S::OPEN, "left", &pair)?.start;
+		let name = Identifier::parse(value)?;
+		let args = match Maybe::<Group<tokens::Paren, A>>::parse(value)? {
+			Maybe::Present(args) => Some(args),
+			Maybe::Absent(_) => None
+		};
+
+		let end = match S::CLOSE {
+			"" => value.position(),
+			close => tokens::parse_fence(value, close, "right", &pair)?.end
+		};
+
+		Ok(Self { name, args, span: Span::new(start, end), sigil: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<S, A> fmt::Debug for Attribute<S, A> where S: AttributeSigil, A: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.args {
+			Some(args) => write!(f, "Attribute({}{:?}{:#?}{}, from {})", S::OPEN, self.name, args.item(), S::CLOSE, self.span),
+			None => write!(f, "Attribute({}{:?}{}, from {})", S::OPEN, self.name, S::CLOSE, self.span)
+		}
+    }
+}
+
+/// Maybe is an optional value that can tell the difference between a value that is absent, and a value that is malformed.
+/// 
+/// A `T` is considered to be started (committed) once its first token has been parsed.
+/// If `T` fails at the position where it would have started, nothing that could start a `T` was found and the result is [`Maybe::Absent`].
+/// If `T` fails further along, the input clearly contained a `T` and the error is returned.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, Identifier, Number, Maybe, Parse, tokens};
+/// # fn main() {
+/// 	type Assign = (Identifier, tokens::Equal, Number);
+/// 
+/// 	let mut buffer = CharStream::new("x = 1".to_owned()).build();
+/// 	let value = Maybe::<Assign>::parse(&mut buffer);
+/// 	assert!(matches!(value, Ok(Maybe::Present(_))));
+/// 
+/// 	let mut buffer = CharStream::new("12".to_owned()).build();
+/// 	let value = Maybe::<Assign>::parse(&mut buffer);
+/// 	assert!(matches!(value, Ok(Maybe::Absent(_))));
+/// 
+/// 	let mut buffer = CharStream::new("x = y".to_owned()).build();
+/// 	let value = Maybe::<Assign>::parse(&mut buffer);
+/// 	assert!(value.is_err());
+/// 
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{Parsable, parsing};
+/// 		#[derive(Parsable, Clone)]
+/// 		struct Let(#[value("let")] Identifier, Identifier, tokens::Equal, Number);
+/// 
+/// 		let mut buffer = CharStream::new("let x = 1".to_owned()).build();
+/// 		assert!(matches!(Maybe::<Let>::parse(&mut buffer), Ok(Maybe::Present(_))));
+/// 
+/// 		let mut buffer = CharStream::new("var x = 1".to_owned()).build();
+/// 		assert!(matches!(Maybe::<Let>::parse(&mut buffer), Ok(Maybe::Absent(_))));
+/// 
+/// 		let mut buffer = CharStream::new("let x = ".to_owned()).build();
+/// 		assert!(Maybe::<Let>::parse(&mut buffer).is_err());
+/// 	}
+/// # }
+/// ```
+/// A trailing optional part, like the `else` of an `if`, is a `Maybe` of the keyword and what follows it.
+/// Once the keyword is found, a malformed rest is an error, instead of the `else` silently being absent:
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Group, Identifier, Maybe, Parse};
+/// # fn main() {
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{Parsable, parsing};
+/// 		#[derive(Parsable, Clone)]
+/// 		struct Else(#[value("else")] Identifier, Group<tokens::Brace, Identifier>);
+/// 
+/// 		#[derive(Parsable, Clone)]
+/// 		struct If(#[value("if")] Identifier, Identifier, Group<tokens::Brace, Identifier>, Maybe<Else>);
+/// 
+/// 		let mut buffer = CharStream::new("if x { y } else { z }".to_owned()).build();
+/// 		assert!(matches!(If::parse(&mut buffer), Ok(If(_, _, _, Maybe::Present(_)))));
+/// 
+/// 		let mut buffer = CharStream::new("if x { y }".to_owned()).build();
+/// 		assert!(matches!(If::parse(&mut buffer), Ok(If(_, _, _, Maybe::Absent(_)))));
+/// 
+/// 		let mut buffer = CharStream::new("if x { y } else z".to_owned()).build();
+/// 		let error = If::parse(&mut buffer).err().unwrap();
+/// 		assert_eq!(format!("{:?}", error), "0:16:Error: 'could not find left side of: '{}'.'");
+/// 	}
+/// # }
+/// ```
+#[derive(Clone)]
+pub enum Maybe<T> where T: Parse {
+	Present(T),
+	Absent(Span)
+}
+
+impl<T> Parse for Maybe<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut maybe_value = value.clone();
+		maybe_value.skip_whitespace();
+		let start = maybe_value.position();
+
+		match T::parse(&mut maybe_value) {
+			Ok(item) => {
+				value.goto(maybe_value.position())?;
+				Ok(Self::Present(item))
+			}
+			Err(error) if error.committed || error.position > start => Err(error),
+			Err(error) => {
+				value.record_failure(&error);
+				Ok(Self::Absent(Span::new(value.position(), value.position())))
+			}
+		}
+    }
+
+    fn span(&self) -> Span {
+        match self {
+			Self::Present(item) => item.span(),
+			Self::Absent(span) => span.clone()
+		}
+    }
+}
+
+impl<T> fmt::Debug for Maybe<T> where T: fmt::Debug + Parse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Present(item) => write!(f, "Present({:#?})", item),
+			Self::Absent(span) => write!(f, "Absent(at {})", span.start)
+		}
+    }
+}
+
+/// WithDefault is an optional value that is `T::default()` when it is absent.
+/// 
+/// It tells absent and malformed values apart the same way [`Maybe`] does, so a malformed value is still an error.
+/// An absent value does not advance the stream, and has an empty span at the position where it would have started.
+/// ```
+/// # use parseal::parsing::{charstream::{CharStream, Span}, Identifier, Number, Parse, ParseError, WithDefault};
+/// # fn main() {
+/// 	#[derive(Clone, Default)]
+/// 	struct Count(usize, Option<Number>);
+/// 
+/// 	impl Parse for Count {
+/// 		fn parse(value: &mut CharStream) -> Result<Self, ParseError> {
+/// 			let number = Number::parse(value)?;
+/// 			Ok(Self(1, Some(number)))
+/// 		}
+/// 
+/// 		fn span(&self) -> Span {
+/// 			self.1.as_ref().unwrap().span()
+/// 		}
+/// 	}
+/// 
+/// 	let mut buffer = CharStream::new("x 3".to_owned()).build();
+/// 	let _name = Identifier::parse(&mut buffer).unwrap();
+/// 	let count = WithDefault::<Count>::parse(&mut buffer).unwrap();
+/// 	assert!(count.is_present());
+/// 	assert_eq!(count.value().0, 1);
+/// 
+/// 	let mut buffer = CharStream::new("x".to_owned()).build();
+/// 	let _name = Identifier::parse(&mut buffer).unwrap();
+/// 	let count = WithDefault::<Count>::parse(&mut buffer).unwrap();
+/// 	assert!(!count.is_present());
+/// 	assert_eq!(count.value().0, 0);
+/// 	assert_eq!(count.span().start, count.span().end);
+/// 	assert_eq!(count.span().start.column(), 1);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WithDefault<T> where T: Parse + Default {
+	item: T,
+	present: bool,
+	span: Span
+}
+
+impl<T> WithDefault<T> where T: Parse + Default {
+	/// Returns the parsed value, or the default value if it was absent.
+	pub fn value(&self) -> &T {
+		&self.item
+	}
+
+	pub fn into_value(self) -> T {
+		self.item
+	}
+
+	/// Returns true when the value was in the input.
+	pub fn is_present(&self) -> bool {
+		self.present
+	}
+}
+
+impl<T> Parse for WithDefault<T> where T: Parse + Default {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		match Maybe::<T>::parse(value)? {
+			Maybe::Present(item) => Ok(Self { span: item.span(), item, present: true }),
+			Maybe::Absent(span) => Ok(Self { item: T::default(), present: false, span })
+		}
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<T> fmt::Debug for WithDefault<T> where T: fmt::Debug + Parse + Default {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.present {
+			true => write!(f, "WithDefault({:#?})", self.item),
+			false => write!(f, "WithDefault(default {:?}, at {})", self.item, self.span.start)
+		}
+    }
+}
+
+/// Prefixed parses `P` and then `T`, and only keeps `T`.
+/// 
+/// The span covers both, but the prefix is mostly noise in a grammar, so Prefixed dereferences to `T`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, Parse, Prefixed, Suffixed};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("!ready".to_owned()).build();
+/// 	let negated = Prefixed::<tokens::Bang, Identifier>::parse(&mut buffer).unwrap();
+/// 	assert!(*negated == "ready");
+/// 	assert_eq!(negated.span().start.column(), 0);
+/// 	assert_eq!(negated.span().end.column(), 6);
+/// 
+/// 	let mut buffer = CharStream::new("!x;".to_owned()).build();
+/// 	let statement = Prefixed::<tokens::Bang, Suffixed<Identifier, tokens::Semicolon>>::parse(&mut buffer).unwrap();
+/// 	assert!(**statement == "x");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Prefixed<P, T> where P: Parse, T: Parse {
+	item: T,
+	span: Span,
+	prefix: PhantomData<P>
+}
+
+impl<P, T> Prefixed<P, T> where P: Parse, T: Parse {
+	pub fn into_inner(self) -> T {
+		self.item
+	}
+}
+
+impl<P, T> Parse for Prefixed<P, T> where P: Parse, T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let prefix = P::parse(value)?;
+		let item = T::parse(value)?;
+		Ok(Self { span: Span::new(prefix.span().start, item.span().end), item, prefix: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<P, T> std::ops::Deref for Prefixed<P, T> where P: Parse, T: Parse {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.item
+	}
+}
+
+impl<P, T> fmt::Debug for Prefixed<P, T> where P: Parse, T: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Prefixed({:#?})", self.item)
+    }
+}
+
+/// Suffixed parses `T` and then `S`, and only keeps `T`.
+/// 
+/// Like [`Prefixed`], the span covers both and Suffixed dereferences to `T`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, Parse, Suffixed};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a; b;".to_owned()).build();
+/// 	let statements = Vec::<Suffixed<Identifier, tokens::Semicolon>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(statements.len(), 2);
+/// 	assert!(*statements[1] == "b");
+/// 	assert_eq!(statements[1].span().end.column(), 5);
+/// 
+/// 	let mut buffer = CharStream::new("a".to_owned()).build();
+/// 	assert!(Suffixed::<Identifier, tokens::Semicolon>::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Suffixed<T, S> where T: Parse, S: Parse {
+	item: T,
+	span: Span,
+	suffix: PhantomData<S>
+}
+
+impl<T, S> Suffixed<T, S> where T: Parse, S: Parse {
+	pub fn into_inner(self) -> T {
+		self.item
+	}
+}
+
+impl<T, S> Parse for Suffixed<T, S> where T: Parse, S: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let item = T::parse(value)?;
+		let suffix = S::parse(value)?;
+		Ok(Self { span: Span::new(item.span().start, suffix.span().end), item, suffix: PhantomData })
+    }
+
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<T, S> std::ops::Deref for Suffixed<T, S> where T: Parse, S: Parse {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.item
+	}
+}
+
+impl<T, S> fmt::Debug for Suffixed<T, S> where T: Parse + fmt::Debug, S: Parse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Suffixed({:#?})", self.item)
+    }
+}
+
+/// Commit marks the point after which an alternative can not be abandoned anymore.
+/// Any error while parsing `T` is [committed](ParseError::commit), 
+/// which makes enums, [`List`], [`Maybe`] and `Vec` report that error instead of backtracking and trying something else.
+/// 
+/// The derive macro can do the same with the `#[commit]` attribute, all fields after a field with this attribute are committed.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, Commit, Identifier, List, Maybe, Number, Parse};
+/// # fn main() {
+/// 	type Object = (tokens::LeftBrace, Commit<(List<Number, tokens::Comma>, tokens::RightBrace)>);
+/// 
+/// 	let mut buffer = CharStream::new("{1, 2 3}".to_owned()).build();
+/// 	let error = Maybe::<Object>::parse(&mut buffer).unwrap_err();
+/// 	assert!(error.is_committed());
+/// 	assert_eq!(error.position().column(), 6);
+/// 
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{Parsable, parsing::{self, StringValue}};
+/// 		#[derive(Parsable, Clone)]
+/// 		struct NamedValue(StringValue, tokens::Colon, Node);
+/// 
+/// 		#[derive(Parsable, Clone)]
+/// 		struct JSONObject(#[commit] tokens::LeftBrace, List<NamedValue, tokens::Comma>, tokens::RightBrace);
+/// 
+/// 		#[derive(Parsable, Clone)]
+/// 		enum Node {
+/// 			Object(JSONObject),
+/// 			Number(Number),
+/// 			Bool(Identifier)
+/// 		}
+/// 
+/// 		let mut buffer = CharStream::new("{\"a\": 1, \"b\" 2}".to_owned()).build();
+/// 		let error = Node::parse(&mut buffer).err().unwrap();
+/// 		// without the commit, this would report that no identifier could be found at the `{`.
+/// 		assert_eq!(format!("{:?}", error), "0:13:Error: 'Could not find token ':'.'");
+/// 
+/// 		let mut buffer = CharStream::new("{\"a\": 1}".to_owned()).build();
+/// 		let node = Node::parse(&mut buffer).ok().unwrap();
+/// 		// derived enums can tell which variant they are.
+/// 		assert_eq!(node.variant_name(), "Object");
+/// 	}
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Commit<T> where T: Parse {
+	item: T
+}
+
+impl<T> Commit<T> where T: Parse {
+	pub fn item(&self) -> &T {
+		&self.item
+	}
+}
 
-			items.push((item, separator));
+impl<T> Parse for Commit<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		match T::parse(value) {
+			Ok(item) => Ok(Self { item }),
+			Err(error) => Err(error.commit())
 		}
-
-		let end = value.position();
-
-		Ok(Self { items, span: Span::new(start, end) })
     }
 
-	fn span(&self) -> Span {
-		self.span.clone()
-	}
+    fn span(&self) -> Span {
+        self.item.span()
+    }
 }
 
-impl<I, S> fmt::Debug for List<I, S> where 
-	I: Parse + fmt::Debug,
-	S: tokens::Token + fmt::Debug
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "List({:#?}, from {})", self.items, self.span())
+impl<T> fmt::Debug for Commit<T> where T: fmt::Debug + Parse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Commit({:#?})", self.item)
     }
 }
 
-/// StringValue represents a string.
-/// this is necessary because it needs to store some additional information for the AST, like the info necessary for [`Parse::span`].
+/// Alternating parses an `A`, followed by any amount of pairs of a `B` and an `A`, like `A B A B A`.
+/// It ends before the first `B` that is not followed by an `A`, so it always starts and ends with an `A`.
+/// This is like a [`List`] where the separators carry data, like the placeholders between the literal parts of a format string.
 /// ```
-/// # use parseal::parsing::{StringValue, Parse, charstream::CharStream};
+/// # use parseal::parsing::{charstream::CharStream, tokens, Alternating, Group, Identifier, Parse};
 /// # fn main() {
-/// 	let mut buffer = CharStream::new("\"Hello, world!\"".to_owned()).build();
-/// 	let value = StringValue::parse(&mut buffer);
+/// 	type Template = Alternating<Identifier, Group<tokens::Brace, Identifier>>;
 /// 
-/// 	assert!(value.is_ok());
+/// 	let mut buffer = CharStream::new("lit {x} lit {y} lit".to_owned()).build();
+/// 	let template = Template::parse(&mut buffer).unwrap();
+/// 	assert_eq!(template.firsts().len(), 3);
+/// 	assert_eq!(template.seconds().len(), 2);
+/// 	assert!(*template.seconds()[1].item() == "y");
+/// 	assert_eq!(template.span().end.column(), 19);
+/// 
+/// 	let mut buffer = CharStream::new("lit {x}".to_owned()).build();
+/// 	let template = Template::parse(&mut buffer).unwrap();
+/// 	assert_eq!(template.firsts().len(), 1);
+/// 	assert!(template.seconds().is_empty());
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct StringValue {
-	delim: tokens::Quote,
-	value: String
+pub struct Alternating<A, B> where A: Parse, B: Parse {
+	firsts: Vec<A>,
+	seconds: Vec<B>
 }
 
-impl Parse for StringValue {
-	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-		let left = <tokens::Quote as tokens::Delimiter>::Start::parse(value)?;
-		let mut inner_value = String::new();
+impl<A, B> Alternating<A, B> where A: Parse, B: Parse {
+	/// Returns all `A`s, there is always one more of these than there are `B`s.
+	pub fn firsts(&self) -> &Vec<A> {
+		&self.firsts
+	}
 
-		let mut string_value = value.clone();
+	/// Returns all `B`s, the `B` at an index is between the `A`s at that index and the next.
+	pub fn seconds(&self) -> &Vec<B> {
+		&self.seconds
+	}
+}
 
-		let mut position = string_value.position();
+impl<A, B> Parse for Alternating<A, B> where A: Parse, B: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut firsts = vec![A::parse(value)?];
+		let mut seconds = Vec::new();
 
-		string_value.set_whitespace(WhitespaceType::KeepAll);
 		loop {
-			match string_value.next() {
-				Some(value) if value != '"' => {
-					inner_value.push(value);
-					position = string_value.position();
+			let mut pair_value = value.clone();
+			match <(B, A)>::parse(&mut pair_value) {
+				Ok((second, first)) => {
+					value.goto(pair_value.position())?;
+					seconds.push(second);
+					firsts.push(first);
+				}
+				Err(error) if error.is_committed() => return Err(error),
+				Err(error) => {
+					value.record_failure(&error);
+					break;
 				}
-				_ => break
 			}
 		}
 
-		value.goto(position)?;
-		
-		let right = <tokens::Quote as tokens::Delimiter>::End::parse(value)?;
-
-		Ok(Self { delim: tokens::Delimiter::new(left, right), value: inner_value})
+		Ok(Self { firsts, seconds })
     }
 
-	fn span(&self) -> Span {
-		self.delim.span()
-	}
+    fn span(&self) -> Span {
+		Span::new(self.firsts[0].span().start, self.firsts.last().unwrap().span().end)
+    }
 }
 
-impl fmt::Debug for StringValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "StringValue({}, from {})", self.value, self.span())
+impl<A, B> fmt::Debug for Alternating<A, B> where A: Parse + fmt::Debug, B: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Alternating({:#?}, {:#?})", self.firsts, self.seconds)
     }
 }
 
-/// An Identifier represents things like words and names.
+/// ManyTill parses `T` repeatedly, until `End` matches.
+/// Before every item it checks for `End`, so the terminator is never parsed as a `T`.
+/// The terminator is consumed, and kept as part of the value.
 /// ```
-/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse, tokens, self};
-/// 
+/// # use parseal::parsing::{charstream::CharStream, tokens, Identifier, ManyTill, Parse};
 /// # fn main() {
-/// 	let buffer = "hello world".to_owned();
-/// 	let mut buffer = CharStream::new(buffer).build();
-/// 	
-/// 	let value = Vec::<Identifier>::parse(&mut buffer).unwrap();
-/// 	assert_eq!(value.len(), 2);
+/// 	type Statement = (Identifier, tokens::Semicolon);
+/// 	type Block = (tokens::LeftBrace, ManyTill<Statement, tokens::RightBrace>);
 /// 
-/// 	#[cfg(feature="derive")]
-/// 	{
-/// 		# use parseal::Parsable;
-/// 		#[derive(Parsable, Clone)]
-/// 		enum Bool {
-/// 			True(#[value("true")] Identifier),
-/// 			False(#[value("false")] Identifier)
-/// 		}
+/// 	let mut buffer = CharStream::new("{ a; b; c; }".to_owned()).build();
+/// 	let (_, block) = Block::parse(&mut buffer).unwrap();
+/// 	assert_eq!(block.items().len(), 3);
+/// 	assert_eq!(block.end().span().start.column(), 11);
 /// 
-/// 		let mut buffer = CharStream::new("true | false".to_owned()).build();
-/// 		let value = <(Bool, tokens::Pipe, Bool)>::parse(&mut buffer);
-/// 		assert!(value.is_ok());
-/// 	}
+/// 	let mut buffer = CharStream::new("{ }".to_owned()).build();
+/// 	let (_, block) = Block::parse(&mut buffer).unwrap();
+/// 	assert!(block.items().is_empty());
+/// 
+/// 	let mut buffer = CharStream::new("{ a; b".to_owned()).build();
+/// 	assert!(Block::parse(&mut buffer).is_err());
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct Identifier {
-	identifier: String,
+pub struct ManyTill<T, End> where T: Parse, End: Parse {
+	items: Vec<T>,
+	end: End,
 	span: Span
 }
 
-impl Parse for Identifier {
-	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-		let mut identifier = String::new();
-		let start = value.position();
+impl<T, End> ManyTill<T, End> where T: Parse, End: Parse {
+	pub fn items(&self) -> &Vec<T> {
+		&self.items
+	}
 
-		let mut ident_value = value.clone();
-		match ident_value.next() {
-			Some(chr) if chr.is_alphabetic() => {
-				let mut position = ident_value.position();
-				identifier.push(chr);
+	pub fn end(&self) -> &End {
+		&self.end
+	}
+}
 
-				ident_value.set_whitespace(WhitespaceType::KeepAll);
+impl<T, End> Parse for ManyTill<T, End> where T: Parse, End: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut items = Vec::new();
 
-				loop {
-					match ident_value.next() {
-						Some(value) if value.is_alphanumeric() => {
-							identifier.push(value);
-							position = ident_value.position();
-						}
-						_ => break
-					}
+		let mut start_value = value.clone();
+		start_value.skip_whitespace();
+		let start = start_value.position();
+
+		loop {
+			let mut end_value = value.clone();
+			let end_error = match End::parse(&mut end_value) {
+				Ok(end) => {
+					value.goto(end_value.position())?;
+					let span = Span::new(start, end.span().end);
+					return Ok(Self { items, end, span });
 				}
+				Err(error) => error
+			};
 
-				value.goto(position)?;
+			match T::parse(value) {
+				Ok(item) => items.push(item),
+				Err(error) if error.position > end_error.position => return Err(error),
+				Err(_) => return Err(end_error)
 			}
-			_ => return Err(ParseError("Did not find identifier".to_string(), ident_value.position()))
 		}
-
-		let end = value.position();
-
-		Ok(Self { identifier , span: Span::new(start, end)})
     }
 
-	fn span(&self) -> Span {
-		self.span.clone()
-	}
-}
-
-impl fmt::Debug for Identifier {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Identifier({}, from {})", self.identifier, self.span)
+    fn span(&self) -> Span {
+        self.span.clone()
     }
 }
 
-impl PartialEq<&str> for Identifier {
-    fn eq(&self, other: &&str) -> bool {
-        self.identifier == other.to_owned()
+impl<T, End> fmt::Debug for ManyTill<T, End> where T: Parse + fmt::Debug, End: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "ManyTill({:#?}, end: {:?}, from {})", self.items, self.end, self.span)
     }
 }
 
-/// A Number is a representation of a number, duh.
-/// this representation is needed since it needs to store some additional information for the AST.
+/// SpaceList is a [`List`] without separators, its items are only separated by whitespace, like `1 2 3`.
+/// Every `I` has to stop at the end of its own item, the list ends at the first position where `I` does not parse.
+/// 
+/// Unlike `Vec<T>`, a SpaceList can be empty, and its span runs from where the list started to where it ended, like a [`List`].
 /// ```
-/// # use parseal::parsing::{Number, Parse, charstream::CharStream};
+/// # use parseal::parsing::{charstream::CharStream, tokens, Number, Parse, SpaceList};
 /// # fn main() {
-/// 	let mut buffer = CharStream::new("69420".to_owned()).build();
-/// 	let value = Number::parse(&mut buffer);
+/// 	let mut buffer = CharStream::new("1 2 3".to_owned()).build();
+/// 	let list = SpaceList::<Number>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 3);
+/// 	assert_eq!(list.span().end.column(), 5);
 /// 
-/// 	assert!(value.is_ok());
+/// 	type Call = (tokens::LeftParen, SpaceList<Number>, tokens::RightParen);
+/// 	let mut buffer = CharStream::new("()".to_owned()).build();
+/// 	let (_, list, _) = Call::parse(&mut buffer).unwrap();
+/// 	assert_eq!(list.items().count(), 0);
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct Number {
-	value: String,
+pub struct SpaceList<I> where I: Parse {
+	items: Vec<I>,
 	span: Span
 }
 
-impl Parse for Number {
-	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-		let mut number = String::new();
-		let start = value.position();
-		
-		let mut num_value = value.clone();
-		match num_value.next() {
-			Some(chr) if chr.is_numeric() => {
-				let mut position = num_value.position();
-				number.push(chr);
+impl<I> SpaceList<I> where I: Parse {
+	pub fn items(&self) -> impl Iterator<Item = &I> {
+		self.items.iter()
+	}
+}
 
-				num_value.set_whitespace(WhitespaceType::KeepAll);
+impl<I> Parse for SpaceList<I> where I: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut items = Vec::new();
+		let start = value.position();
 
-				loop {
-					match num_value.next() {
-						Some(value) if value.is_numeric() => {
-							number.push(value);
-							position = num_value.position();
-						}
-						_ => break
-					}
+		loop {
+			let mut item_value = value.clone();
+			match I::parse(&mut item_value) {
+				// an item that matched nothing would be matched forever.
+				Ok(_) if item_value.position() == value.position() => break,
+				Ok(item) => {
+					value.goto(item_value.position())?;
+					items.push(item);
+				}
+				Err(error) if error.is_committed() => return Err(error),
+				Err(error) => {
+					value.record_failure(&error);
+					break;
 				}
-
-				value.goto(position)?;
 			}
-			_ => return Err(ParseError("Did not find number".to_string(), num_value.position()))
 		}
 
 		let end = value.position();
 
-		Ok(Number { value: number, span: Span::new(start, end)})
+		Ok(Self { items, span: Span::new(start, end) })
     }
 
-	fn span(&self) -> Span {
-		self.span.clone()
-	}
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
 }
 
-impl fmt::Debug for Number {
+impl<I> fmt::Debug for SpaceList<I> where I: Parse + fmt::Debug {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Number({}, from {})", self.value, self.span)
+		write!(f, "SpaceList({:#?}, from {})", self.items, self.span)
     }
 }
 
+/// Recoverable parses a `T`, and keeps the error instead of failing when it can not.
+/// When `T` can not be parsed, the error is kept and the input is skipped up to the next character of `S` (see [`Terminators`]),
+/// so parsing can continue after the malformed part. The terminator itself is not consumed.
+/// 
+/// Only when there is nothing to skip, at the end of the input or right at a terminator, the error is returned (and never committed),
+/// so a `Vec` of Recoverable values stops there instead of repeating forever.
+/// 
+/// This is useful for tolerant parsing, where a single mistake should not hide the rest of the result.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens, CommaOrLineEnd, Identifier, Number, Parse, Recoverable};
+/// # fn main() {
+/// 	type Setting = (Identifier, tokens::Equal, Recoverable<Number>);
+/// 
+/// 	let mut buffer = CharStream::new("a = x y\nb = 2".to_owned()).build();
+/// 	let (_, _, first) = Setting::parse(&mut buffer).unwrap();
+/// 	assert!(first.value().is_none());
+/// 	assert_eq!(first.error().unwrap().position().column(), 4);
+/// 
+/// 	let (name, _, second) = Setting::parse(&mut buffer).unwrap();
+/// 	assert!(name == "b");
+/// 	assert_eq!(second.value().unwrap().span().start.column(), 4);
+/// 
+/// 	let mut buffer = CharStream::new("a = x\nb = 2".to_owned()).build();
+/// 	let settings = Vec::<Recoverable<(Identifier, tokens::Equal, Number)>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(settings.len(), 2);
+/// 	assert!(settings[0].error().is_some());
+/// 	assert!(settings[1].value().is_some());
+/// 	assert!(buffer.is_at_end());
+/// 
+/// 	let mut buffer = CharStream::new(", 2".to_owned()).build();
+/// 	assert!(Recoverable::<Number, CommaOrLineEnd>::parse(&mut buffer).is_err());
+/// 
+/// 	#[cfg(feature="derive")]
+/// 	{
+/// 		# use parseal::{Parsable, parsing};
+/// 		#[derive(Parsable, Clone)]
+/// 		struct Point(tokens::LeftParen, Recoverable<Number, parsing::CommaOrLineEnd>, tokens::Comma, Number, tokens::RightParen);
+/// 
+/// 		let mut buffer = CharStream::new("(oops, 2)".to_owned()).build();
+/// 		let point = Point::parse(&mut buffer).unwrap();
+/// 		assert!(point.1.result().is_err());
+/// 		assert_eq!(point.3.span().start.column(), 7);
+/// 	}
+/// # }
+/// ```
 #[derive(Clone)]
-pub struct Indent<T> {
-	values: Vec<T>,
-	depth: u8
+pub struct Recoverable<T, S = LineEnd> where T: Parse, S: Terminators {
+	result: Result<T, ParseError>,
+	span: Span,
+	sync: PhantomData<S>
 }
 
-impl<T> Parse for Indent<T> where T: Parse {
-    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-        let mut values = Vec::new();
+impl<T, S> Recoverable<T, S> where T: Parse, S: Terminators {
+	pub fn result(&self) -> &Result<T, ParseError> {
+		&self.result
+	}
 
-		let mut indent_value = value.clone();
-		indent_value.set_whitespace(WhitespaceType::Indent);
-		let mut position = indent_value.position();
+	pub fn value(&self) -> Option<&T> {
+		self.result.as_ref().ok()
+	}
 
-		let mut item = T::parse(&mut indent_value);
-		let depth= indent_value.indent();
-		while item.is_ok() {
-			position = indent_value.position();
-			values.push(item?);
-			item = T::parse(&mut indent_value);
+	pub fn error(&self) -> Option<&ParseError> {
+		self.result.as_ref().err()
+	}
+}
 
-			if indent_value.indent() != depth {
-				break;
+impl<T, S> Parse for Recoverable<T, S> where T: Parse, S: Terminators {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut item_value = value.clone();
+		let error = match T::parse(&mut item_value) {
+			Ok(item) => {
+				value.goto(item_value.position())?;
+				let span = item.span();
+				return Ok(Self { result: Ok(item), span, sync: PhantomData });
 			}
+			Err(error) => error
+		};
+
+		let mut skip_value = value.clone();
+		skip_value.skip_whitespace();
+		let start = skip_value.position();
+		if error.position > start {
+			skip_value.goto(error.position.clone())?;
 		}
+		skip_value.set_whitespace(WhitespaceType::KeepAll);
 
-		if values.len() == 0 {
-			Err(ParseError("Could not find Indent block.".to_string(), position))
-		} else {
-			Ok(Self { values, depth })
+		loop {
+			let mut next_value = skip_value.clone();
+			match next_value.next() {
+				Some(chr) if !S::TERMINATORS.contains(&chr) => skip_value = next_value,
+				_ => break
+			}
+		}
+
+		let end = skip_value.position();
+		if end == start {
+			return Err(ParseError { committed: false, ..error });
 		}
+
+		value.goto(end.clone())?;
+		Ok(Self { result: Err(error), span: Span::new(start, end), sync: PhantomData })
     }
 
     fn span(&self) -> Span {
-        Span::new(self.values.first().unwrap().span().start, self.values.last().unwrap().span().end)
+        self.span.clone()
     }
 }
 
-impl<T> fmt::Debug for Indent<T> where T: fmt::Debug + Parse {
+impl<T, S> fmt::Debug for Recoverable<T, S> where T: Parse + fmt::Debug, S: Terminators {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "Indent({:#?}, from {}, depth {})", self.values, self.span(), self.depth)
+		match &self.result {
+			Ok(item) => write!(f, "Recoverable({:#?})", item),
+			Err(error) => write!(f, "Recoverable({:?}, skipped {})", error, self.span)
+		}
     }
 }
 
@@ -419,8 +3604,15 @@ impl<T> Parse for Vec<T> where T: Parse {
 			item = T::parse(value);
 		}
 
-		if vec.len() == 0 {
-			Err(ParseError("Could not find vector.".to_string(), value.position()))
+		if let Err(error) = item {
+			if error.is_committed() {
+				return Err(error);
+			}
+			value.record_failure(&error);
+		}
+
+		if vec.is_empty() {
+			Err(ParseError::new("Could not find vector.", value.position()))
 		} else {
 			Ok(vec)
 		}
@@ -441,7 +3633,7 @@ impl<T, const N: usize> Parse for [T; N] where T: Parse + fmt::Debug {
 
 		match <[T; N]>::try_from(result) {
 			Ok(result) => Ok(result),
-			Err(error) => Err(ParseError(format!("Could not create slice from parsed values. \nvalues where: {:?}", error), value.position()))
+			Err(error) => Err(ParseError::new(&format!("Could not create slice from parsed values. \nvalues where: {:?}", error), value.position()))
 		}
     }
 
@@ -484,3 +3676,54 @@ impl<A, B, C> Parse for (A, B, C) where
 		Span::new(self.0.span().start, self.2.span().end)
 	}
 }
+
+/// A boxed or shared `T`, a `Box` is needed for types that contain themselves, like the operands of an expression,
+/// and an `Rc` or `Arc` lets a parsed value be referenced from multiple places without copying it.
+/// ```
+/// # use std::{rc::Rc, sync::Arc};
+/// # use parseal::parsing::{charstream::CharStream, Identifier, Number, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("7".to_owned()).build();
+/// 	let boxed = Box::<Number>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(boxed.span().end.column(), 1);
+/// 
+/// 	let mut buffer = CharStream::new("12".to_owned()).build();
+/// 	let number = Rc::<Number>::parse(&mut buffer).unwrap();
+/// 	let shared = number.clone();
+/// 	assert!(Rc::ptr_eq(&number, &shared));
+/// 	assert_eq!(number.span().end.column(), 2);
+/// 
+/// 	let mut buffer = CharStream::new("name".to_owned()).build();
+/// 	let identifier = Arc::<Identifier>::parse(&mut buffer).unwrap();
+/// 	assert!(*identifier == "name");
+/// # }
+/// ```
+impl<T> Parse for Box<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        Ok(Box::new(T::parse(value)?))
+    }
+
+	fn span(&self) -> Span {
+		self.as_ref().span()
+	}
+}
+
+impl<T> Parse for Rc<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        Ok(Rc::new(T::parse(value)?))
+    }
+
+	fn span(&self) -> Span {
+		self.as_ref().span()
+	}
+}
+
+impl<T> Parse for Arc<T> where T: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        Ok(Arc::new(T::parse(value)?))
+    }
+
+	fn span(&self) -> Span {
+		self.as_ref().span()
+	}
+}
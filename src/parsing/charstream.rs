@@ -1,21 +1,54 @@
-use std::{vec::IntoIter, fmt};
+use std::{any::{Any, TypeId}, rc::Rc, cell::{Cell, RefCell}, collections::HashMap, fmt};
 use rand::random;
 
 use super::ParseError;
 
+/// Position is a location in the buffer of a [`CharStream`].
+/// 
+/// Positions can only be created by a stream, the fields can be read but not changed.
+/// This guarantees that every position given to [`CharStream::goto`] has a row and column that match its index.
+/// ```compile_fail
+/// # use parseal::parsing::charstream::{CharStream, Position};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("hello".to_owned()).build();
+/// 	let mut position = buffer.position();
+/// 	position.row = 10;
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct Position {
-	pub column: usize,
-	pub	row: usize,
-	pub	index: usize,
-	pub	file: Option<String>,
-	pub file_id: u32
+	column: usize,
+	row: usize,
+	index: usize,
+	file: Option<String>,
+	file_id: u32
 }
 
 impl Position {
+	/// The column on the current row, starting at 0.
+	pub fn column(&self) -> usize {
+		self.column
+	}
+
+	/// The row in the buffer, starting at 0.
+	pub fn row(&self) -> usize {
+		self.row
+	}
+
+	/// The amount of characters before this position.
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// The name of the file of the buffer, if it was set.
+	pub fn file(&self) -> Option<&str> {
+		self.file.as_deref()
+	}
+
 	pub fn end(value: &str, file: Option<String>, file_id: u32) -> Position {
 		let mut column = 0;
 		let mut row = 0;
+		let mut index = 0;
 
 		for c in value.chars() {
 			match c {
@@ -25,9 +58,10 @@ impl Position {
 				}
 				_ => column += 1,
 			}
+			index += 1;
 		}
 
-		Self { column, row, index: value.len(), file, file_id }
+		Self { column, row, index, file, file_id }
 	}
 }
 
@@ -69,6 +103,96 @@ impl Span {
 	pub fn new(start: Position, end: Position) -> Self {
 		Self { start, end }
 	}
+
+	/// Returns the whole line of `source` that the span starts on, without the newline.
+	/// `source` has to be the buffer the span was parsed from.
+	/// ```
+	/// # use parseal::parsing::{charstream::{CharStream, Span}, Identifier, Number, Parse};
+	/// # fn main() {
+	/// 	let source = "a\nb 12\nc";
+	/// 	let mut buffer = CharStream::new(source.to_owned()).build();
+	/// 	let (a, _, number) = <(Identifier, Identifier, Number)>::parse(&mut buffer).unwrap();
+	/// 	assert_eq!(number.span().line_text(source), "b 12");
+	/// 
+	/// 	let span = Span::new(a.span().start, number.span().end);
+	/// 	assert_eq!(span.line_text(source), "a");
+	/// 	assert_eq!(span.lines_text(source), "a\nb 12");
+	/// 
+	/// 	let _ = Identifier::parse(&mut buffer).unwrap();
+	/// 	let eof = Span::new(buffer.position(), buffer.position());
+	/// 	assert_eq!(eof.line_text(source), "c");
+	/// # }
+	/// ```
+	pub fn line_text<'a>(&self, source: &'a str) -> &'a str {
+		let start = Self::line_start(source, self.start.row);
+		&source[start..Self::line_end(source, start)]
+	}
+
+	/// Returns all lines of `source` that the span touches, from the start of its first line to the end of its last line.
+	pub fn lines_text<'a>(&self, source: &'a str) -> &'a str {
+		let start = Self::line_start(source, self.start.row);
+		let end = Self::line_end(source, Self::line_start(source, self.end.row));
+		&source[start..end.max(start)]
+	}
+
+	/// Returns the span from the start of the first non-empty span to the end of the last non-empty span.
+	/// Empty spans, like the span of an absent [`Maybe`](super::Maybe), are skipped. 
+	/// If every span is empty, the first one is returned, and without any spans there is nothing to cover.
+	/// 
+	/// This is the span that the derive macro gives a struct, so optional fields do not stretch it.
+	/// ```
+	/// # use parseal::parsing::{charstream::{CharStream, Span}, Identifier, Maybe, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("  a".to_owned()).build();
+	/// 	let (number, identifier) = <(Maybe<Number>, Identifier)>::parse(&mut buffer).unwrap();
+	/// 	let span = Span::covering([number.span(), identifier.span()]).unwrap();
+	/// 	assert_eq!(span, identifier.span());
+	/// 	assert!(Span::covering([]).is_none());
+	/// 
+	/// 	#[cfg(feature="derive")]
+	/// 	{
+	/// 		# use parseal::{Parsable, parsing};
+	/// 		#[derive(Clone, Parsable)]
+	/// 		struct Options(Maybe<Number>, Maybe<Identifier>);
+	/// 
+	/// 		let mut buffer = CharStream::new("12".to_owned()).build();
+	/// 		let options = Options::parse(&mut buffer).unwrap();
+	/// 		assert_eq!(options.span().start.column(), 0);
+	/// 		assert_eq!(options.span().end.column(), 2);
+	/// 
+	/// 		let options = Options::parse(&mut buffer).unwrap();
+	/// 		assert_eq!(options.span().start, options.span().end);
+	/// 		assert_eq!(options.span().start.column(), 2);
+	/// 	}
+	/// # }
+	/// ```
+	pub fn covering<I>(spans: I) -> Option<Self> where I: IntoIterator<Item = Span> {
+		let mut spans = spans.into_iter();
+		let first = spans.next()?;
+		let filled = |span: &Span| span.start != span.end;
+
+		let covered = spans.fold(filled(&first).then(|| first.clone()), |covered, span| match (covered, filled(&span)) {
+			(Some(covered), true) => Some(Self::new(covered.start, span.end)),
+			(None, true) => Some(span),
+			(covered, false) => covered
+		});
+		Some(covered.unwrap_or(first))
+	}
+
+	fn line_start(source: &str, row: usize) -> usize {
+		match row {
+			0 => 0,
+			row => source.match_indices('\n').nth(row - 1).map_or(source.len(), |(index, _)| index + 1)
+		}
+	}
+
+	fn line_end(source: &str, start: usize) -> usize {
+		let end = source[start..].find('\n').map_or(source.len(), |index| start + index);
+		match source[..end].ends_with('\r') {
+			true => end - 1,
+			false => end
+		}
+	}
 }
 
 impl PartialOrd for Span {
@@ -87,50 +211,275 @@ impl fmt::Display for Span {
 	}
 }
 
-#[derive(Debug, Clone)]
+/// WhitespaceType decides what whitespace a [`CharStream`] skips before reading a character.
+/// ```
+/// # use parseal::parsing::{charstream::{CharStream, WhitespaceType}, tokens::{Equal, Punct}, Identifier, List, Number, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new(" \n a".to_owned()).whitespace(WhitespaceType::Ignore).build();
+/// 	assert_eq!(buffer.next(), Some('a'));
+/// 
+/// 	let mut buffer = CharStream::new(" \n a".to_owned()).whitespace(WhitespaceType::KeepAll).build();
+/// 	assert_eq!(buffer.next(), Some(' '));
+/// 
+/// 	let mut buffer = CharStream::new(" \n a".to_owned()).whitespace(WhitespaceType::SpacesOnly).build();
+/// 	assert_eq!(buffer.next(), Some('\n'));
+/// 
+/// 	let mut buffer = CharStream::new("a\n\t  b".to_owned()).whitespace(WhitespaceType::Indent).build();
+/// 	assert_eq!(buffer.next(), Some('a'));
+/// 	assert_eq!(buffer.next(), Some('b'));
+/// 	assert_eq!(buffer.indent(), 6);
+/// 
+/// 	// with `SpacesOnly`, a newline can separate the lines of a line-oriented format.
+/// 	type Line = (Identifier, Equal, Number);
+/// 
+/// 	let mut buffer = CharStream::new("a = 1\nb = 2".to_owned()).whitespace(WhitespaceType::SpacesOnly).build();
+/// 	let lines = List::<Line, Punct<'\n'>>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(lines.items().count(), 2);
+/// 
+/// 	let mut buffer = CharStream::new("a =\n1".to_owned()).whitespace(WhitespaceType::SpacesOnly).build();
+/// 	assert!(Line::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WhitespaceType {
+	/// Skips all whitespace, including newlines. This is the default.
 	Ignore,
+	/// Skips nothing, every character is significant. Comments are not skipped either.
 	KeepAll,
-	Indent
+	/// Skips all whitespace like `Ignore`, but keeps track of the indentation of the current line, see [`CharStream::indent`].
+	/// A space counts as 1, a tab as the width set with [`CharStreamBuilder::tab_width`].
+	Indent,
+	/// Skips spaces and tabs, but not newlines, so a newline is read as a character.
+	SpacesOnly
+}
+
+/// The comments that a [`CharStream`] skips together with whitespace.
+/// Comments are skipped in every [`WhitespaceType`] except `KeepAll`.
+#[derive(Debug, Clone, Default)]
+pub struct CommentStyle {
+	line: Option<Vec<char>>,
+	block: Option<(Vec<char>, Vec<char>)>
 }
 
+impl CommentStyle {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Comments that start with `start` and run until the end of the line, like `// comment`.
+	pub fn line(mut self, start: &str) -> Self {
+		self.line = Some(start.chars().collect());
+		self
+	}
+
+	/// Comments that start with `start` and end with `end`, like `/* comment */`.
+	pub fn block(mut self, start: &str, end: &str) -> Self {
+		self.block = Some((start.chars().collect(), end.chars().collect()));
+		self
+	}
+}
+
+/// A step of parsing, reported to the tracer set with [`CharStreamBuilder::trace`].
+#[derive(Debug, Clone)]
+pub enum Trace<'a> {
+	/// Parsing of the type `name` starts at `position`.
+	Enter { name: &'static str, position: Position, depth: usize },
+	/// Parsing of the type `name` ended at `position`, with the error if it failed.
+	Exit { name: &'static str, position: Position, depth: usize, error: Option<&'a ParseError> }
+}
+
+#[derive(Clone)]
+struct Tracer {
+	callback: Rc<dyn Fn(&Trace)>,
+	depth: Rc<Cell<usize>>
+}
+
+impl fmt::Debug for Tracer {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Tracer(depth: {})", self.depth.get())
+	}
+}
+
+type MemoKey = (TypeId, usize, WhitespaceType, usize);
+
+#[derive(Clone, Default)]
+struct Memo {
+	results: Rc<RefCell<HashMap<MemoKey, Rc<dyn Any>>>>
+}
+
+impl fmt::Debug for Memo {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Memo({} results)", self.results.borrow().len())
+	}
+}
+
+/// CharStreamBuilder holds the options of a [`CharStream`].
+/// ```
+/// # use parseal::parsing::{charstream::{CharStream, CommentStyle, WhitespaceType}, Identifier, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("// comment\nhello /* inline */ world".to_owned())
+/// 		.whitespace(WhitespaceType::Ignore)
+/// 		.tab_width(2)
+/// 		.comments(CommentStyle::new().line("//").block("/*", "*/"))
+/// 		.max_depth(64)
+/// 		.build();
+/// 
+/// 	let value = Vec::<Identifier>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.len(), 2);
+/// 	assert!(value[1] == "world");
+/// # }
+/// ```
 pub struct CharStreamBuilder {
 	buffer: String,
 	file: Option<String>,
 	file_id: u32,
-	indent_size: u8
+	indent_size: u8,
+	whitespace: WhitespaceType,
+	comments: CommentStyle,
+	max_depth: Option<usize>,
+	max_length: Option<usize>,
+	max_nodes: Option<usize>,
+	tracer: Option<Tracer>,
+	memoize: bool
 }
 
 impl CharStreamBuilder {
 	pub fn new(buffer: String) -> Self {
-		Self { buffer, file: None, file_id: random(), indent_size: 4 }
+		Self { 
+			buffer, 
+			file: None, 
+			file_id: random(), 
+			indent_size: 4, 
+			whitespace: WhitespaceType::Ignore, 
+			comments: CommentStyle::default(), 
+			max_depth: None,
+			max_length: None,
+			max_nodes: None,
+			tracer: None,
+			memoize: false
+		}
+	}
+
+	/// Sets the name of the file the buffer was read from, it is shown in positions.
+	pub fn file(&mut self, file: &str) -> &mut Self {
+		self.file = Some(file.to_string());
+		self
+	}
+
+	/// Sets the [`WhitespaceType`] the stream starts with, the default is `Ignore`.
+	pub fn whitespace(&mut self, whitespace: WhitespaceType) -> &mut Self {
+		self.whitespace = whitespace;
+		self
+	}
+
+	/// Sets how much indentation a tab counts for, the default is 4.
+	pub fn tab_width(&mut self, width: u8) -> &mut Self {
+		self.indent_size = width;
+		self
+	}
+
+	/// Sets the comments that are skipped together with whitespace, by default there are none.
+	pub fn comments(&mut self, comments: CommentStyle) -> &mut Self {
+		self.comments = comments;
+		self
+	}
+
+	/// Sets how deep delimited groups can be nested, by default there is no limit.
+	pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+		self.max_depth = Some(depth);
+		self
+	}
+
+	/// Sets how many characters the input can have, by default there is no limit.
+	/// The length is checked once, when the stream is built or [reset](CharStream::reset).
+	/// An input that is too long is not kept, the stream reads as if it were empty,
+	/// and the first [`CharStream::count_node`] returns the error for it.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("12345".to_owned()).max_length(4).build();
+	/// 	assert_eq!(buffer.next(), None);
+	/// 
+	/// 	let error = Number::parse(&mut buffer).unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'The input can not be longer than 4 characters.'");
+	/// 
+	/// 	buffer.reset("1234".to_owned());
+	/// 	assert!(Number::parse(&mut buffer).is_ok());
+	/// # }
+	/// ```
+	pub fn max_length(&mut self, length: usize) -> &mut Self {
+		self.max_length = Some(length);
+		self
+	}
+
+	/// Sets a callback that is called when parsing of a type starts and ends, see [`CharStream::trace`].
+	/// By default nothing is traced.
+	pub fn trace(&mut self, callback: impl Fn(&Trace) + 'static) -> &mut Self {
+		self.tracer = Some(Tracer { callback: Rc::new(callback), depth: Rc::new(Cell::new(0)) });
+		self
+	}
+
+	/// Caches the results of [`CharStream::memoize`], so a type is parsed at most once at every position.
+	/// By default nothing is cached.
+	pub fn memoize(&mut self) -> &mut Self {
+		self.memoize = true;
+		self
+	}
+
+	/// Sets how many nodes can be parsed from the input, by default there is no limit.
+	/// See [`CharStream::count_node`] for what counts as a node.
+	pub fn max_nodes(&mut self, nodes: usize) -> &mut Self {
+		self.max_nodes = Some(nodes);
+		self
 	}
 
 	pub fn build(&mut self) -> CharStream {
 		let buffer = self.buffer.clone();
-		let chars = buffer.chars().collect::<Vec<_>>().into_iter();
+		let bom = buffer.starts_with('\u{FEFF}');
+		let buffer = buffer.strip_prefix('\u{FEFF}').unwrap_or(&buffer);
+		let too_long = CharStream::exceeds(buffer, self.max_length);
+		let buffer = match too_long {
+			true => "",
+			false => buffer
+		};
+		let chars = Rc::new(buffer.chars().collect::<Vec<_>>());
+		let lines = Rc::new(CharStream::line_starts(&chars).collect());
 		let file = self.file.clone();
-		let eof = Position::end(&buffer, file.clone(), self.file_id);
+		let eof = Position::end(buffer, file.clone(), self.file_id);
 
 		CharStream { 
 			chars, 
+			lines,
 			file, 
 			file_id: self.file_id, 
 			column: 0, 
 			row: 0, 
 			index: 0, 
 			eof, 
-			whitespace: WhitespaceType::Ignore, 
+			whitespace: self.whitespace.clone(), 
 			indent: 0, 
 			indent_size: self.indent_size, 
-			in_indent: true 
+			in_indent: true,
+			comments: Rc::new(self.comments.clone()),
+			depth: 0,
+			max_depth: self.max_depth,
+			furthest: Rc::new(RefCell::new(None)),
+			bom,
+			max_length: self.max_length,
+			too_long,
+			max_nodes: self.max_nodes,
+			nodes: Rc::new(Cell::new(0)),
+			tracer: self.tracer.clone(),
+			deepest: Rc::new(Cell::new(0)),
+			memo: self.memoize.then(Memo::default)
 		}
 	}
 }
 
 #[derive(Debug, Clone)]
 pub struct CharStream {
-	chars: IntoIter<char>,
+	chars: Rc<Vec<char>>,
+	lines: Rc<Vec<usize>>,
 	file: Option<String>,
 	file_id: u32,
 	column: usize,
@@ -140,20 +489,416 @@ pub struct CharStream {
 	whitespace: WhitespaceType,
 	indent: u8,
 	indent_size: u8,
-	in_indent: bool
+	in_indent: bool,
+	comments: Rc<CommentStyle>,
+	depth: usize,
+	max_depth: Option<usize>,
+	furthest: Rc<RefCell<Option<ParseError>>>,
+	bom: bool,
+	max_length: Option<usize>,
+	too_long: bool,
+	max_nodes: Option<usize>,
+	nodes: Rc<Cell<usize>>,
+	tracer: Option<Tracer>,
+	deepest: Rc<Cell<usize>>,
+	memo: Option<Memo>
 }
 
 impl CharStream {
+	#[allow(clippy::new_ret_no_self)]
 	pub fn new(value: String) -> CharStreamBuilder {
 		CharStreamBuilder::new(value)
 	}
 
+	/// Creates a [`CharStreamBuilder`] from bytes that should be UTF-8.
+	/// Returns an error at the first invalid sequence, its message includes the byte offset of that sequence.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::from_bytes(b"hello".to_vec()).unwrap().build();
+	/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "hello");
+	/// 
+	/// 	let error = CharStream::from_bytes(b"ab\nc\xFFd".to_vec()).err().unwrap();
+	/// 	assert_eq!(format!("{:?}", error), "1:1:Error: 'The input is not valid UTF-8, found an invalid sequence at byte 4.'");
+	/// 
+	/// 	let mut buffer = CharStream::from_bytes_lossy(b"c\xFFd").build();
+	/// 	assert_eq!(buffer.next(), Some('c'));
+	/// 	assert_eq!(buffer.next(), Some(char::REPLACEMENT_CHARACTER));
+	/// # }
+	/// ```
+	pub fn from_bytes(value: Vec<u8>) -> Result<CharStreamBuilder, ParseError> {
+		match String::from_utf8(value) {
+			Ok(value) => Ok(CharStreamBuilder::new(value)),
+			Err(error) => {
+				let offset = error.utf8_error().valid_up_to();
+				let valid = std::str::from_utf8(&error.as_bytes()[..offset]).unwrap_or_default();
+				Err(ParseError::new(
+					&format!("The input is not valid UTF-8, found an invalid sequence at byte {}.", offset), 
+					Position::end(valid, None, 0)
+				))
+			}
+		}
+	}
+
+	/// Creates a [`CharStreamBuilder`] from bytes, every invalid UTF-8 sequence is replaced with [`char::REPLACEMENT_CHARACTER`].
+	pub fn from_bytes_lossy(value: &[u8]) -> CharStreamBuilder {
+		CharStreamBuilder::new(String::from_utf8_lossy(value).into_owned())
+	}
+
 	pub fn position(&self) -> Position {
 		Position { column: self.column, row: self.row, index: self.index, file: self.file.clone(), file_id: self.file_id }
 	}
 
+	/// Replaces the buffer of the stream with `source`, and moves to its start.
+	/// The options of the stream, like its comments and limits, are kept, so a stream can be reused for many inputs.
+	/// Positions from the old buffer can not be used with the new one.
+	/// 
+	/// The memory of the old buffer is reused for the new one, unless a clone of the stream still holds on to it.
+	/// ```
+	/// # use parseal::parsing::{charstream::{CharStream, CommentStyle}, Identifier, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new(String::new()).comments(CommentStyle::new().line("#")).build();
+	/// 	for (source, expected) in [("# one\n1", 1), ("22 # two", 2), ("\n\n333", 3)] {
+	/// 		buffer.reset(source.to_owned());
+	/// 		let number = Number::parse(&mut buffer).unwrap();
+	/// 		assert_eq!(number.span().end.column() - number.span().start.column(), expected);
+	/// 	}
+	/// 
+	/// 	// rewinding parses the same input again, with another grammar.
+	/// 	let mut buffer = CharStream::new("abc".to_owned()).build();
+	/// 	assert!(Number::parse(&mut buffer).is_err());
+	/// 	buffer.rewind();
+	/// 	assert!(Identifier::parse(&mut buffer).unwrap() == "abc");
+	/// 	buffer.rewind();
+	/// 	assert_eq!(buffer.position().index(), 0);
+	/// # }
+	/// ```
+	pub fn reset(&mut self, source: String) {
+		self.bom = source.starts_with('\u{FEFF}');
+		let source = source.strip_prefix('\u{FEFF}').unwrap_or(&source);
+		self.too_long = Self::exceeds(source, self.max_length);
+		let source = match self.too_long {
+			true => "",
+			false => source
+		};
+
+		match Rc::get_mut(&mut self.chars) {
+			Some(chars) => {
+				chars.clear();
+				chars.extend(source.chars());
+			}
+			None => self.chars = Rc::new(source.chars().collect())
+		}
+		match Rc::get_mut(&mut self.lines) {
+			Some(lines) => {
+				lines.clear();
+				lines.extend(Self::line_starts(&self.chars));
+			}
+			None => self.lines = Rc::new(Self::line_starts(&self.chars).collect())
+		}
+
+		self.file_id = random();
+		self.eof = Position::end(source, self.file.clone(), self.file_id);
+		self.rewind();
+	}
+
+	/// Moves the stream back to the start of its buffer, and forgets everything that was parsed, like failures and cached results.
+	/// The buffer itself is kept.
+	pub fn rewind(&mut self) {
+		self.index = 0;
+		self.row = 0;
+		self.column = 0;
+		self.indent = 0;
+		self.in_indent = true;
+		self.depth = 0;
+
+		match Rc::get_mut(&mut self.furthest) {
+			Some(furthest) => *furthest.get_mut() = None,
+			None => self.furthest = Rc::new(RefCell::new(None))
+		}
+		match Rc::get_mut(&mut self.nodes) {
+			Some(nodes) => nodes.set(0),
+			None => self.nodes = Rc::new(Cell::new(0))
+		}
+		match Rc::get_mut(&mut self.deepest) {
+			Some(deepest) => deepest.set(0),
+			None => self.deepest = Rc::new(Cell::new(0))
+		}
+		if let Some(memo) = &mut self.memo {
+			match Rc::get_mut(&mut memo.results) {
+				Some(results) => results.get_mut().clear(),
+				None => *memo = Memo::default()
+			}
+		}
+	}
+
+	/// The index of the first character of every line in `chars`.
+	fn line_starts(chars: &[char]) -> impl Iterator<Item = usize> + '_ {
+		std::iter::once(0).chain(chars.iter().enumerate().filter(|(_, chr)| **chr == '\n').map(|(index, _)| index + 1))
+	}
+
+	/// Returns true if the buffer started with a byte order mark (`\u{FEFF}`).
+	/// The mark is skipped when the stream is built, so it is not part of any position.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Group, List, Number, Parse, StringValue};
+	/// # fn main() {
+	/// 	type Object = Group<tokens::Brace, List<(StringValue, tokens::Colon, Number), tokens::Comma>>;
+	/// 
+	/// 	let mut plain = CharStream::new("{\"a\": 1, \"b\": 2}".to_owned()).build();
+	/// 	let mut marked = CharStream::new("\u{FEFF}{\"a\": 1, \"b\": 2}".to_owned()).build();
+	/// 	assert!(!plain.has_bom());
+	/// 	assert!(marked.has_bom());
+	/// 
+	/// 	let plain = Object::parse(&mut plain).unwrap();
+	/// 	let marked = Object::parse(&mut marked).unwrap();
+	/// 	assert_eq!(marked.span().start.column(), 0);
+	/// 	assert_eq!(plain.span().end.index(), marked.span().end.index());
+	/// # }
+	/// ```
+	pub fn has_bom(&self) -> bool {
+		self.bom
+	}
+
+	/// Returns true when every character of the buffer has been read.
+	pub fn is_at_end(&self) -> bool {
+		self.index >= self.chars.len()
+	}
+
+	/// Remembers an error that was recovered from, if it got further than any error recorded before.
+	/// The record is shared by all clones of this stream, so failed alternatives are remembered after backtracking.
+	pub fn record_failure(&self, error: &ParseError) {
+		let mut furthest = self.furthest.borrow_mut();
+		match &*furthest {
+			Some(previous) if previous.position().index() >= error.position().index() => {}
+			_ => *furthest = Some(error.clone())
+		}
+	}
+
+	/// Returns whichever got further, the given error or the furthest recorded failure.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Number, Parse};
+	/// # fn main() {
+	/// 	#[cfg(feature="derive")]
+	/// 	{
+	/// 		# use parseal::{Parsable, parsing};
+	/// 		#[derive(Parsable, Clone)]
+	/// 		enum Value {
+	/// 			Single(Number),
+	/// 			Range(Number, tokens::Colon, Number)
+	/// 		}
+	/// 
+	/// 		#[derive(Parsable, Clone)]
+	/// 		struct Line(Value, tokens::Semicolon);
+	/// 
+	/// 		let mut buffer = CharStream::new("1 : x;".to_owned()).build();
+	/// 		let error = Line::parse(&mut buffer).err().unwrap();
+	/// 		// only `Single` matched, so the `;` was expected after the `1`.
+	/// 		assert_eq!(error.position().column(), 2);
+	/// 
+	/// 		let error = buffer.furthest_failure(error);
+	/// 		// but `Range` got up to the `x`, where a number was expected.
+	/// 		assert_eq!(error.position().column(), 4);
+	/// 	}
+	/// # }
+	/// ```
+	pub fn furthest_failure(&self, error: ParseError) -> ParseError {
+		match &*self.furthest.borrow() {
+			Some(furthest) if furthest.position().index() > error.position().index() => furthest.clone(),
+			_ => error
+		}
+	}
+
+	/// The offset of the position in the buffer, in bytes instead of characters.
+	pub fn byte_offset(&self, position: &Position) -> usize {
+		self.chars[..position.index.min(self.chars.len())].iter().map(|chr| chr.len_utf8()).sum()
+	}
+
+	/// Returns the position of the next significant character, the position the stream would be at after [`CharStream::skip_whitespace`].
+	/// Comparing it with [`CharStream::position`] tells if whitespace comes next, without consuming it.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Number, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("1\n".to_owned()).build();
+	/// 	let _ = Number::parse(&mut buffer).unwrap();
+	/// 	assert!(buffer.newline_ahead());
+	/// 	assert_eq!(buffer.peek_position().row(), 1);
+	/// 
+	/// 	let mut buffer = CharStream::new("1 2".to_owned()).build();
+	/// 	let _ = Number::parse(&mut buffer).unwrap();
+	/// 	assert!(!buffer.newline_ahead());
+	/// 	assert_eq!(buffer.position().column(), 1);
+	/// 	assert_eq!(buffer.peek_position().column(), 2);
+	/// # }
+	/// ```
+	pub fn peek_position(&self) -> Position {
+		let mut ahead = self.clone();
+		ahead.skip_whitespace();
+		ahead.position()
+	}
+
+	/// Checks that all `()`, `{}` and `[]` in the whole buffer are balanced, ignoring those inside `"` strings.
+	/// This can be done before parsing, to point at the first unmatched delimiter instead of wherever parsing fails.
+	/// ```
+	/// # use parseal::parsing::charstream::CharStream;
+	/// # fn main() {
+	/// 	let buffer = CharStream::new("{\"a\": [1, \"}\"]}".to_owned()).build();
+	/// 	assert!(buffer.check_balanced().is_ok());
+	/// 
+	/// 	let buffer = CharStream::new("{\"a\": 1}}".to_owned()).build();
+	/// 	let error = buffer.check_balanced().unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "0:8:Error: 'Unbalanced '}', there is nothing to close.'");
+	/// 
+	/// 	let buffer = CharStream::new("{\n\t\"a\": [1, 2\n}".to_owned()).build();
+	/// 	let error = buffer.check_balanced().unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "2:0:Error: 'Unbalanced '}', expected ']' to close the '[' from 1:6.'");
+	/// 
+	/// 	let buffer = CharStream::new("[[1, 2]".to_owned()).build();
+	/// 	let error = buffer.check_balanced().unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'Unbalanced '[', it is never closed.'");
+	/// # }
+	/// ```
+	pub fn check_balanced(&self) -> Result<(), ParseError> {
+		let mut open: Vec<(char, Position)> = Vec::new();
+		let mut in_string = false;
+		let mut escaped = false;
+		let (mut row, mut column) = (0, 0);
+
+		for (index, &chr) in self.chars.iter().enumerate() {
+			let position = Position { column, row, index, file: self.file.clone(), file_id: self.file_id };
+			match chr {
+				'\n' => {
+					row += 1;
+					column = 0;
+				}
+				_ => column += 1
+			}
+
+			if in_string {
+				match chr {
+					_ if escaped => escaped = false,
+					'\\' => escaped = true,
+					'"' => in_string = false,
+					_ => {}
+				}
+				continue;
+			}
+
+			let expected = match chr {
+				'"' => {
+					in_string = true;
+					continue;
+				}
+				'(' | '{' | '[' => {
+					open.push((chr, position));
+					continue;
+				}
+				')' => '(',
+				'}' => '{',
+				']' => '[',
+				_ => continue
+			};
+
+			match open.pop() {
+				Some((opener, _)) if opener == expected => {}
+				Some((opener, from)) => {
+					let closer = match opener {
+						'(' => ')',
+						'{' => '}',
+						_ => ']'
+					};
+					return Err(ParseError::new(&format!("Unbalanced '{}', expected '{}' to close the '{}' from {}.", chr, closer, opener, from), position));
+				}
+				None => return Err(ParseError::new(&format!("Unbalanced '{}', there is nothing to close.", chr), position))
+			}
+		}
+
+		match open.pop() {
+			Some((opener, from)) => Err(ParseError::new(&format!("Unbalanced '{}', it is never closed.", opener), from)),
+			None => Ok(())
+		}
+	}
+
+	/// Returns true if a newline comes before the next significant character.
+	pub fn newline_ahead(&self) -> bool {
+		let mut ahead = self.clone();
+		ahead.skip_whitespace();
+		ahead.row != self.row || ahead.chars.get(ahead.index) == Some(&'\n')
+	}
+
+	#[allow(clippy::should_implement_trait)]
 	pub fn next(&mut self) -> Option<char> {
-		let chr = match self.chars.next() {
+		self.skip_whitespace();
+		let chr = self.advance();
+
+		if let WhitespaceType::Indent = self.whitespace {
+			self.in_indent = false;
+		}
+
+		chr
+	}
+
+	/// Moves the stream past any whitespace that the current [`WhitespaceType`] would skip,
+	/// without consuming the next significant character.
+	/// This makes [`CharStream::position`] point at the start of whatever will be read next.
+	pub fn skip_whitespace(&mut self) {
+		if let WhitespaceType::KeepAll = self.whitespace {
+			return;
+		}
+
+		let comments = self.comments.clone();
+		loop {
+			let rest = &self.chars[self.index..];
+
+			if let Some(line) = &comments.line {
+				if rest.starts_with(line) {
+					while !matches!(self.chars.get(self.index), Some('\n') | None) {
+						self.advance();
+					}
+					continue;
+				}
+			}
+
+			if let Some((start, end)) = &comments.block {
+				if rest.starts_with(start) {
+					for _ in 0..start.len() {
+						self.advance();
+					}
+					while !self.chars[self.index..].starts_with(end) && self.advance().is_some() {}
+					for _ in 0..end.len() {
+						self.advance();
+					}
+					continue;
+				}
+			}
+
+			let chr = match rest.first() {
+				Some('\n') if matches!(self.whitespace, WhitespaceType::SpacesOnly) => break,
+				Some(&chr) if chr.is_whitespace() => chr,
+				_ => break
+			};
+			self.advance();
+
+			if let WhitespaceType::Indent = self.whitespace {
+				match chr {
+					'\t' if self.in_indent => self.indent += self.indent_size,
+					' ' if self.in_indent => self.indent += 1,
+					'\n' => {
+						self.in_indent = true;
+						self.indent = 0;
+					}
+					_ => {}
+				}
+			}
+		}
+	}
+
+	/// Returns true if `source` has more characters than `max_length`, without counting past it.
+	fn exceeds(source: &str, max_length: Option<usize>) -> bool {
+		max_length.is_some_and(|max_length| source.chars().nth(max_length).is_some())
+	}
+
+	fn advance(&mut self) -> Option<char> {
+		match self.chars.get(self.index).copied() {
 			Some('\n') => {
 				self.index += 1;
 				self.column = 0;
@@ -166,64 +911,61 @@ impl CharStream {
 				Some(value)
 			}
 			None => None
-		};
-
-		match self.whitespace {
-			WhitespaceType::Ignore => {
-				match chr {
-					Some(c) if c.is_whitespace() => {
-						self.next()
-					}
-					c => c
-				}
-			}
-			WhitespaceType::KeepAll => chr,
-			WhitespaceType::Indent => {
-				match chr {
-					Some(c) if c.is_whitespace() => {
-						match c {
-							'\t' => {
-								if self.in_indent {
-									self.indent += self.indent_size;
-								}
-							}
-							' ' => {
-								if self.in_indent {
-									self.indent += 1;
-								}
-							}
-							'\n' => {
-								self.in_indent = true;
-								self.indent = 0;
-							}
-							_ => {}
-						}
-						self.next()
-					}
-					c => {
-						self.in_indent = false;
-						c
-					}
-				}
-			}
 		}
 	}
 
+	/// Moves the stream to the given position.
+	/// 
+	/// The position has to be one that this stream (or a clone of it) has produced through [`CharStream::position`],
+	/// both going forwards and going back to an earlier position are supported.
+	/// Positions from a different buffer are rejected, and so are positions after the end of the buffer 
+	/// or positions whose row and column do not match their index, since they would desynchronize the stream.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, Identifier, Parse};
+	/// # fn main() {
+	/// 	let mut buffer = CharStream::new("hello\nworld".to_owned()).build();
+	/// 	let start = buffer.position();
+	/// 
+	/// 	let _ = <[Identifier; 2]>::parse(&mut buffer).unwrap();
+	/// 	let end = buffer.position();
+	/// 
+	/// 	assert!(buffer.goto(start.clone()).is_ok());
+	/// 	assert_eq!(buffer.position(), start);
+	/// 	assert!(buffer.goto(end.clone()).is_ok());
+	/// 	assert_eq!(buffer.position(), end);
+	/// 
+	/// 	let other = CharStream::new("hello\nworld".to_owned()).build();
+	/// 	assert!(buffer.goto(other.position()).is_err());
+	/// # }
+	/// ```
 	pub fn goto(&mut self, position: Position) -> Result<(), ParseError> {
 		if self.file_id != position.file_id {
-			return Err(ParseError("Could not go to position in different buffer.".to_string(), position));
+			return Err(ParseError::new("Could not go to position in different buffer.", position));
 		}
 
-		if position < self.position() {
-			return Err(ParseError("Charstream does not support going back.".to_string(), position));
+		if position.index > self.chars.len() {
+			return Err(ParseError::new("Charstream can not go to position after end of buffer.", self.eof.clone()));
 		}
 
-		if position > self.eof {
-			return Err(ParseError("Charstream can not go to position after end of buffer.".to_string(), self.eof.clone()));
+		let row = self.lines.partition_point(|start| *start <= position.index) - 1;
+		let line_start = self.lines[row];
+		if position.row != row || position.column != position.index - line_start {
+			return Err(ParseError::new("Position was not produced by this charstream.", position));
 		}
 
-		while self.position() < position {
-			self.next();
+		self.index = position.index;
+		self.row = row;
+		self.column = position.column;
+
+		self.indent = 0;
+		self.in_indent = true;
+		for chr in &self.chars[line_start..self.index] {
+			match chr {
+				'\t' if self.in_indent => self.indent += self.indent_size,
+				' ' if self.in_indent => self.indent += 1,
+				chr if chr.is_whitespace() => {}
+				_ => self.in_indent = false
+			}
 		}
 
 		Ok(())
@@ -233,7 +975,215 @@ impl CharStream {
 		self.whitespace = whitespace;
 	}
 
+	/// Returns the current [`WhitespaceType`], which decides what [`CharStream::skip_whitespace`] skips.
+	pub fn whitespace(&self) -> &WhitespaceType {
+		&self.whitespace
+	}
+
 	pub fn indent(&self) -> u8 {
 		self.indent
 	}
+
+	/// Enters a nested value, like the inside of a [`super::Group`].
+	/// Returns an error when this goes deeper than the maximum depth set with [`CharStreamBuilder::max_depth`].
+	pub fn descend(&mut self) -> Result<(), ParseError> {
+		if let Some(max_depth) = self.max_depth {
+			if self.depth >= max_depth {
+				return Err(ParseError::new(&format!("Values can not be nested more than {} deep.", max_depth), self.position()));
+			}
+		}
+
+		self.depth += 1;
+		Ok(())
+	}
+
+	/// Leaves a nested value that was entered with [`CharStream::descend`].
+	pub fn ascend(&mut self) {
+		self.depth = self.depth.saturating_sub(1);
+	}
+
+	/// Runs `parse` one level deeper, like [`CharStream::descend`] and [`CharStream::ascend`] around it.
+	/// Besides the value, it returns how many levels deep values were nested inside of it, counting itself.
+	/// Only values that were parsed successfully through this function count, so a failed attempt does not change the depth.
+	pub fn nested<T>(&mut self, parse: impl FnOnce(&mut CharStream) -> Result<T, ParseError>) -> Result<(T, usize), ParseError> {
+		self.descend()?;
+		let outer = self.deepest.replace(0);
+		let level = self.depth;
+
+		let result = parse(self);
+		self.ascend();
+
+		match result {
+			Ok(item) => {
+				let reached = self.deepest.get().max(level);
+				self.deepest.set(outer.max(reached));
+				Ok((item, reached - level + 1))
+			}
+			Err(error) => {
+				self.deepest.set(outer);
+				Err(error)
+			}
+		}
+	}
+
+	/// Runs `parse`, or returns the result it had before at the same position, when memoization is turned on with [`CharStreamBuilder::memoize`].
+	/// Results are cached by the type of the value, the position, the [`WhitespaceType`] and the nesting depth, so `parse` has to parse the same type every time.
+	/// A cached result also remembers how deeply values were nested inside of it, so [`CharStream::nested`] counts the same depth either way.
+	/// Without memoization, this only runs `parse`.
+	/// 
+	/// With the `memoize` feature, the derive macro parses every derived type through this function.
+	/// This stops grammars with a lot of backtracking from parsing the same value over and over again.
+	/// ```
+	/// # use std::cell::Cell;
+	/// # use parseal::parsing::{charstream::CharStream, Number, Parse};
+	/// # fn main() {
+	/// 	let calls = Cell::new(0);
+	/// 	let mut buffer = CharStream::new("12".to_owned()).memoize().build();
+	/// 	for _ in 0..3 {
+	/// 		let number = buffer.clone().memoize(|value| {
+	/// 			calls.set(calls.get() + 1);
+	/// 			Number::parse(value)
+	/// 		});
+	/// 		assert_eq!(number.unwrap().span().end.column(), 2);
+	/// 	}
+	/// 	assert_eq!(calls.get(), 1);
+	/// 
+	/// 	#[cfg(feature="derive")]
+	/// 	{
+	/// 		# use parseal::{Parsable, parsing::{self, tokens, Group, Identifier, List}};
+	/// 		#[derive(Parsable, Clone, Debug)]
+	/// 		enum Value {
+	/// 			Call(Identifier, tokens::Colon, Number),
+	/// 			Name(Identifier),
+	/// 			Number(Number)
+	/// 		}
+	/// 
+	/// 		let input = "a: 1, b, 2, c: 3".to_owned();
+	/// 		let plain = List::<Value, tokens::Comma>::parse(&mut CharStream::new(input.clone()).build());
+	/// 		let memoized = List::<Value, tokens::Comma>::parse(&mut CharStream::new(input).memoize().build());
+	/// 		assert_eq!(format!("{:?}", plain), format!("{:?}", memoized));
+	/// 
+	/// 		// `B` finds the `Inner` that `A` parsed before it failed in the cache, at the same depth.
+	/// 		#[derive(Parsable, Clone, Debug)]
+	/// 		struct Inner(Group<tokens::Paren, Identifier>);
+	/// 
+	/// 		#[derive(Parsable, Clone, Debug)]
+	/// 		enum Outer {
+	/// 			A(Group<tokens::Paren, (Inner, tokens::Comma)>),
+	/// 			B(Group<tokens::Paren, Inner>)
+	/// 		}
+	/// 
+	/// 		let depth = |mut buffer: CharStream| match Outer::parse(&mut buffer).unwrap() {
+	/// 			Outer::A(group) => group.depth(),
+	/// 			Outer::B(group) => group.depth()
+	/// 		};
+	/// 		assert_eq!(depth(CharStream::new("((x))".to_owned()).build()), 2);
+	/// 		assert_eq!(depth(CharStream::new("((x))".to_owned()).memoize().build()), 2);
+	/// 	}
+	/// # }
+	/// ```
+	pub fn memoize<T>(&mut self, parse: impl FnOnce(&mut CharStream) -> Result<T, ParseError>) -> Result<T, ParseError> where T: Clone + 'static {
+		let memo = match &self.memo {
+			Some(memo) => memo.clone(),
+			None => return parse(self)
+		};
+
+		let key = (TypeId::of::<T>(), self.index, self.whitespace.clone(), self.depth);
+		let cached = memo.results.borrow().get(&key).cloned();
+		if let Some((result, reached)) = cached.as_ref().and_then(|result| result.downcast_ref::<(Result<(T, Position), ParseError>, usize)>()) {
+			self.deepest.set(self.deepest.get().max(*reached));
+			let (item, end) = result.clone()?;
+			self.goto(end)?;
+			return Ok(item);
+		}
+
+		let outer = self.deepest.replace(0);
+		let result = parse(self);
+		let reached = self.deepest.get();
+		self.deepest.set(outer.max(reached));
+
+		let entry = match &result {
+			Ok(item) => Ok((item.clone(), self.position())),
+			Err(error) => Err(error.clone())
+		};
+		memo.results.borrow_mut().insert(key, Rc::new((entry, reached)));
+		result
+	}
+
+	/// Runs `parse`, and reports it as parsing the type `name` to the tracer set with [`CharStreamBuilder::trace`].
+	/// Without a tracer, this only runs `parse`.
+	/// 
+	/// With the `trace` feature, the derive macro parses every derived type through this function.
+	/// ```
+	/// # use std::{cell::RefCell, rc::Rc};
+	/// # use parseal::parsing::{charstream::{CharStream, Trace}, Number, Parse};
+	/// # fn main() {
+	/// 	let steps = Rc::new(RefCell::new(Vec::new()));
+	/// 	let trace_steps = steps.clone();
+	/// 	let mut buffer = CharStream::new("12 x".to_owned())
+	/// 		.trace(move |trace| trace_steps.borrow_mut().push(match trace {
+	/// 			Trace::Enter { name, depth, .. } => format!("{}enter {}", " ".repeat(*depth), name),
+	/// 			Trace::Exit { name, depth, error, .. } => format!("{}exit {} {}", " ".repeat(*depth), name, error.is_none())
+	/// 		}))
+	/// 		.build();
+	/// 
+	/// 	let _ = buffer.trace("pair", |value| <(Number, Number)>::parse(value));
+	/// 	assert_eq!(*steps.borrow(), ["enter pair", "exit pair false"]);
+	/// # }
+	/// ```
+	pub fn trace<T>(&mut self, name: &'static str, parse: impl FnOnce(&mut CharStream) -> Result<T, ParseError>) -> Result<T, ParseError> {
+		let tracer = match &self.tracer {
+			Some(tracer) => tracer.clone(),
+			None => return parse(self)
+		};
+
+		let depth = tracer.depth.get();
+		(tracer.callback)(&Trace::Enter { name, position: self.peek_position(), depth });
+		tracer.depth.set(depth + 1);
+
+		let result = parse(self);
+
+		tracer.depth.set(depth);
+		(tracer.callback)(&Trace::Exit { name, position: self.position(), depth, error: result.as_ref().err() });
+		result
+	}
+
+	/// Counts a node, and checks the limit set with [`CharStreamBuilder::max_nodes`].
+	/// When the input was longer than [`CharStreamBuilder::max_length`], this returns the error for that instead.
+	/// Every attempt to parse a token, identifier, number or other single value counts as a node, even if it is backtracked.
+	/// The count is shared by all clones of the stream.
+	/// 
+	/// The errors are [committed](ParseError::commit), so no alternative is tried after a limit is exceeded.
+	/// ```
+	/// # use parseal::parsing::{charstream::CharStream, tokens, Group, List, Number, Parse};
+	/// # fn main() {
+	/// 	type Array = Group<tokens::Bracket, List<Number, tokens::Comma>>;
+	/// 
+	/// 	let mut buffer = CharStream::new("[1, 2, 3, 4, 5]".to_owned()).max_length(10).build();
+	/// 	let error = Array::parse(&mut buffer).unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'The input can not be longer than 10 characters.'");
+	/// 
+	/// 	let mut buffer = CharStream::new("[1, 2, 3, 4, 5]".to_owned()).max_nodes(5).build();
+	/// 	let error = Array::parse(&mut buffer).unwrap_err();
+	/// 	assert_eq!(format!("{:?}", error), "0:7:Error: 'Can not parse more than 5 values.'");
+	/// 
+	/// 	let mut buffer = CharStream::new("[1, 2, 3, 4, 5]".to_owned()).max_length(20).max_nodes(20).build();
+	/// 	assert!(Array::parse(&mut buffer).is_ok());
+	/// # }
+	/// ```
+	pub fn count_node(&self) -> Result<(), ParseError> {
+		if let (true, Some(max_length)) = (self.too_long, self.max_length) {
+			return Err(ParseError::new(&format!("The input can not be longer than {} characters.", max_length), self.position()).commit());
+		}
+
+		let nodes = self.nodes.get() + 1;
+		self.nodes.set(nodes);
+		if let Some(max_nodes) = self.max_nodes {
+			if nodes > max_nodes {
+				return Err(ParseError::new(&format!("Can not parse more than {} values.", max_nodes), self.peek_position()).commit());
+			}
+		}
+
+		Ok(())
+	}
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use super::{charstream::{CharStream, Position}, Identifier, Number, Parse, ParseError, StringValue};
+
+/// FromAst converts a parsed syntax tree into a domain type.
+/// This keeps the grammar (the types implementing [`Parse`]) separate from the values a program actually works with.
+/// ```
+/// # use parseal::parsing::{convert::{self, FromAst, ConvertError}, Number, Parse, charstream::CharStream};
+/// # fn main() {
+/// 	struct Port(u16);
+/// 
+/// 	impl FromAst<Number> for Port {
+/// 		fn from_ast(value: Number) -> Result<Self, ConvertError> {
+/// 			let position = value.span().start;
+/// 			match u16::from_ast(value) {
+/// 				Ok(port) if port != 0 => Ok(Port(port)),
+/// 				_ => Err(ConvertError::new("Port has to be between 1 and 65535.", position))
+/// 			}
+/// 		}
+/// 	}
+/// 
+/// 	let port = convert::parse_into::<Number, Port>("8080".to_owned()).unwrap();
+/// 	assert_eq!(port.0, 8080);
+/// 
+/// 	assert!(convert::parse_into::<Number, Port>("0".to_owned()).is_err());
+/// 	assert!(convert::parse_into::<Number, Port>("port".to_owned()).is_err());
+/// # }
+/// ```
+pub trait FromAst<A: Parse>: Sized {
+	fn from_ast(value: A) -> Result<Self, ConvertError>;
+}
+
+/// Parses an `A` from the source, and then converts it into a `T` using [`FromAst`].
+/// If parsing fails, the error is the [furthest failure](CharStream::furthest_failure) any alternative reached.
+pub fn parse_into<A, T>(source: String) -> Result<T, ParseError> where
+	A: Parse,
+	T: FromAst<A>
+{
+	let mut buffer = CharStream::new(source).build();
+	let value = A::parse(&mut buffer).map_err(|error| buffer.furthest_failure(error))?;
+
+	Ok(T::from_ast(value)?)
+}
+
+/// Any error that can be returned by [`FromAst::from_ast`].
+#[derive(Clone)]
+pub struct ConvertError(String, Position);
+
+impl ConvertError {
+	pub fn new(cause: &str, position: Position) -> Self {
+		Self(cause.to_string(), position)
+	}
+}
+
+impl fmt::Debug for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}:{}:Error: '{}'", self.1.row(), self.1.column(), self.0)
+    }
+}
+
+impl From<ConvertError> for ParseError {
+    fn from(value: ConvertError) -> Self {
+        ParseError::new(&value.0, value.1)
+    }
+}
+
+impl FromAst<StringValue> for String {
+    fn from_ast(value: StringValue) -> Result<Self, ConvertError> {
+        Ok(value.value)
+    }
+}
+
+impl FromAst<Identifier> for String {
+    fn from_ast(value: Identifier) -> Result<Self, ConvertError> {
+        Ok(value.identifier)
+    }
+}
+
+macro_rules! number_conversions {
+    ($($ty:ty),+) => {
+        $(
+            impl FromAst<Number> for $ty {
+                fn from_ast(value: Number) -> Result<Self, ConvertError> {
+                    value.value.parse().map_err(|_| ConvertError::new(
+                        &format!("Could not convert '{}' to {}.", value.value, stringify!($ty)),
+                        value.span.start
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+number_conversions!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
@@ -0,0 +1,130 @@
+use std::{any::Any, collections::HashMap, fmt};
+
+use super::{charstream::{CharStream, Span}, Identifier, Key, Parse, ParseError};
+
+type DynParse = Box<dyn Fn(&mut CharStream) -> Result<Box<dyn Any>, ParseError>>;
+
+/// DynParser chooses a parser at runtime, based on the keyword at the start of the input.
+/// 
+/// Parsers are registered for a keyword, and are called with the stream after that keyword.
+/// This makes it possible to add commands without recompiling, like for plugins,
+/// at the cost of returning the parsed value as a [`Box<dyn Any>`].
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, dynamic::DynParser, Number, StringValue};
+/// # fn main() {
+/// 	let mut commands = DynParser::new();
+/// 	commands
+/// 		.register_type::<(Number, Number)>("move")
+/// 		.register_type::<StringValue>("say");
+/// 
+/// 	let mut buffer = CharStream::new("move 3 4".to_owned()).build();
+/// 	let command = commands.parse(&mut buffer).unwrap();
+/// 	assert_eq!(command.keyword(), "move");
+/// 	assert!(command.downcast_ref::<(Number, Number)>().is_some());
+/// 
+/// 	let mut buffer = CharStream::new("say \"hi\"".to_owned()).build();
+/// 	let command = commands.parse(&mut buffer).unwrap();
+/// 	assert_eq!(command.keyword(), "say");
+/// 	assert_eq!(command.downcast_ref::<StringValue>().unwrap().value(), "hi");
+/// 
+/// 	let mut buffer = CharStream::new("jump 1".to_owned()).build();
+/// 	let error = commands.parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'Unknown keyword 'jump', expected one of: move, say.'");
+/// # }
+/// ```
+pub struct DynParser {
+	parsers: HashMap<String, DynParse>
+}
+
+impl DynParser {
+	pub fn new() -> Self {
+		Self { parsers: HashMap::new() }
+	}
+
+	/// Registers a parser for a keyword, it replaces any parser that was registered for the same keyword.
+	pub fn register(&mut self, keyword: &str, parse: impl Fn(&mut CharStream) -> Result<Box<dyn Any>, ParseError> + 'static) -> &mut Self {
+		self.parsers.insert(keyword.to_owned(), Box::new(parse));
+		self
+	}
+
+	/// Registers a keyword that is followed by a `T`.
+	pub fn register_type<T>(&mut self, keyword: &str) -> &mut Self where T: Parse + 'static {
+		self.register(keyword, |value| Ok(Box::new(T::parse(value)?)))
+	}
+
+	/// Returns the registered keywords, in alphabetical order.
+	pub fn keywords(&self) -> Vec<&str> {
+		let mut keywords = self.parsers.keys().map(String::as_str).collect::<Vec<_>>();
+		keywords.sort_unstable();
+		keywords
+	}
+
+	/// Parses a keyword, and then the value of the parser that was registered for it.
+	pub fn parse(&self, value: &mut CharStream) -> Result<Dispatched, ParseError> {
+		value.count_node()?;
+		let mut dyn_value = value.clone();
+		let keyword = Identifier::parse(&mut dyn_value)?;
+
+		let parse = match self.parsers.get(keyword.key()) {
+			Some(parse) => parse,
+			None => return Err(ParseError::new(
+				&format!("Unknown keyword '{}', expected one of: {}.", keyword.key(), self.keywords().join(", ")),
+				keyword.span().start
+			))
+		};
+
+		let item = parse(&mut dyn_value)?;
+
+		value.goto(dyn_value.position())?;
+		let span = Span::new(keyword.span().start, value.position());
+		Ok(Dispatched { keyword, item, span })
+	}
+}
+
+impl Default for DynParser {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Debug for DynParser {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "DynParser({})", self.keywords().join(", "))
+	}
+}
+
+/// The result of a [`DynParser`], the keyword and the value that was parsed after it.
+pub struct Dispatched {
+	keyword: Identifier,
+	item: Box<dyn Any>,
+	span: Span
+}
+
+impl Dispatched {
+	pub fn keyword(&self) -> &str {
+		self.keyword.key()
+	}
+
+	pub fn value(&self) -> &dyn Any {
+		self.item.as_ref()
+	}
+
+	/// Returns the value if it is a `T`.
+	pub fn downcast_ref<T>(&self) -> Option<&T> where T: 'static {
+		self.item.downcast_ref()
+	}
+
+	pub fn into_value(self) -> Box<dyn Any> {
+		self.item
+	}
+
+	pub fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Dispatched {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Dispatched({}, from {})", self.keyword(), self.span)
+	}
+}
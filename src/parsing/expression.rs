@@ -0,0 +1,110 @@
+use std::fmt;
+
+use super::{charstream::{CharStream, Span}, tokens::{self, AndAnd, Bang, OrOr}, Group, Parse, ParseError};
+
+/// A BoolExpr is a boolean expression over atoms of type `A`, like `a && b || !c`.
+/// 
+/// `!` binds the strongest, then `&&`, then `||`. Both `&&` and `||` are left associative,
+/// and an expression can be put between parentheses to change the order.
+/// This is useful for filters and `where` clauses, where `A` is a comparison or a name.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, expression::BoolExpr, Identifier, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("a && b || !c".to_owned()).build();
+/// 	let expr = BoolExpr::<Identifier>::parse(&mut buffer).unwrap();
+/// 	match &expr {
+/// 		BoolExpr::Or(left, _, right) => {
+/// 			assert!(matches!(left.as_ref(), BoolExpr::And(a, _, b) if matches!((a.as_ref(), b.as_ref()), (BoolExpr::Atom(_), BoolExpr::Atom(_)))));
+/// 			assert!(matches!(right.as_ref(), BoolExpr::Not(_, c) if matches!(c.as_ref(), BoolExpr::Atom(c) if *c == "c")));
+/// 		}
+/// 		_ => panic!("expected `||` to bind the weakest")
+/// 	}
+/// 	assert_eq!(expr.span().end.column(), 12);
+/// 
+/// 	let mut buffer = CharStream::new("a && (b || c)".to_owned()).build();
+/// 	assert!(matches!(BoolExpr::<Identifier>::parse(&mut buffer), Ok(BoolExpr::And(..))));
+/// 
+/// 	let mut buffer = CharStream::new("a && ".to_owned()).build();
+/// 	assert!(BoolExpr::<Identifier>::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub enum BoolExpr<A> where A: Parse {
+	Atom(A),
+	Not(Bang, Box<BoolExpr<A>>),
+	And(Box<BoolExpr<A>>, AndAnd, Box<BoolExpr<A>>),
+	Or(Box<BoolExpr<A>>, OrOr, Box<BoolExpr<A>>),
+	Group(Group<tokens::Paren, Box<BoolExpr<A>>>)
+}
+
+impl<A> BoolExpr<A> where A: Parse {
+	fn or(value: &mut CharStream) -> Result<Self, ParseError> {
+		let mut expr = Self::and(value)?;
+		while let Some(operator) = Self::operator::<OrOr>(value)? {
+			expr = Self::Or(Box::new(expr), operator, Box::new(Self::and(value)?));
+		}
+		Ok(expr)
+	}
+
+	fn and(value: &mut CharStream) -> Result<Self, ParseError> {
+		let mut expr = Self::unary(value)?;
+		while let Some(operator) = Self::operator::<AndAnd>(value)? {
+			expr = Self::And(Box::new(expr), operator, Box::new(Self::unary(value)?));
+		}
+		Ok(expr)
+	}
+
+	fn unary(value: &mut CharStream) -> Result<Self, ParseError> {
+		if let Some(bang) = Self::operator::<Bang>(value)? {
+			return Ok(Self::Not(bang, Box::new(Self::unary(value)?)));
+		}
+
+		let mut group_value = value.clone();
+		match tokens::LeftParen::parse(&mut group_value) {
+			Ok(_) => Ok(Self::Group(Group::parse(value)?)),
+			Err(_) => Ok(Self::Atom(A::parse(value)?))
+		}
+	}
+
+	/// Parses the operator `O` if it is next, without moving the stream when it is not.
+	fn operator<O>(value: &mut CharStream) -> Result<Option<O>, ParseError> where O: tokens::Token {
+		let mut operator_value = value.clone();
+		match O::parse(&mut operator_value) {
+			Ok(operator) => {
+				value.goto(operator_value.position())?;
+				Ok(Some(operator))
+			}
+			Err(error) => {
+				value.record_failure(&error);
+				Ok(None)
+			}
+		}
+	}
+}
+
+impl<A> Parse for BoolExpr<A> where A: Parse {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		Self::or(value)
+    }
+
+    fn span(&self) -> Span {
+		match self {
+			Self::Atom(atom) => atom.span(),
+			Self::Not(bang, expr) => Span::new(bang.span().start, expr.span().end),
+			Self::And(left, _, right) | Self::Or(left, _, right) => Span::new(left.span().start, right.span().end),
+			Self::Group(group) => group.span()
+		}
+    }
+}
+
+impl<A> fmt::Debug for BoolExpr<A> where A: Parse + fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Atom(atom) => write!(f, "{:?}", atom),
+			Self::Not(_, expr) => write!(f, "Not({:#?})", expr),
+			Self::And(left, _, right) => write!(f, "And({:#?}, {:#?})", left, right),
+			Self::Or(left, _, right) => write!(f, "Or({:#?}, {:#?})", left, right),
+			Self::Group(group) => write!(f, "{:#?}", group.item())
+		}
+    }
+}
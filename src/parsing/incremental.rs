@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use super::{charstream::CharStream, Parse, ParseError, ParseErrorKind};
+
+/// The result of feeding input to an [`Incremental`] parser.
+#[derive(Debug)]
+pub enum Feed<T> {
+	/// A complete value was parsed.
+	Done(T),
+	/// The input so far is the start of a valid value, but more input is needed to complete it.
+	NeedMore
+}
+
+/// Incremental parses a value from input that arrives in chunks, like bytes read from a network connection.
+/// 
+/// Every call to [`Incremental::feed`] parses the buffered input from the start.
+/// When the parse fails because the input ended (an error of kind [`ParseErrorKind::UnexpectedEof`], or any error at the end of the input),
+/// [`Feed::NeedMore`] is returned instead of the error.
+/// A value that reaches all the way to the end of the input might still continue in the next chunk (think of the number `12` followed by `3`),
+/// so it is only returned once more input follows it, or when [`Incremental::finish`] is called.
+/// 
+/// Any input after a parsed value is kept, and is used for the next value.
+/// ```
+/// # use parseal::parsing::{incremental::{Incremental, Feed}, tokens, Group, List, Number};
+/// # fn main() {
+/// 	let mut parser = Incremental::<Group<tokens::Bracket, List<Number, tokens::Comma>>>::new();
+/// 
+/// 	assert!(matches!(parser.feed("[1, 2"), Ok(Feed::NeedMore)));
+/// 	assert!(matches!(parser.feed(", 3] "), Ok(Feed::Done(_))));
+/// 
+/// 	assert!(matches!(parser.feed("[1, }"), Err(_)));
+/// # }
+/// ```
+pub struct Incremental<T> where T: Parse {
+	buffer: String,
+	item: PhantomData<T>
+}
+
+impl<T> Incremental<T> where T: Parse {
+	pub fn new() -> Self {
+		Self { buffer: String::new(), item: PhantomData }
+	}
+
+	/// Adds a chunk of input, and tries to parse a value from all input that has not been used yet.
+	pub fn feed(&mut self, chunk: &str) -> Result<Feed<T>, ParseError> {
+		self.buffer.push_str(chunk);
+		self.parse(false)
+	}
+
+	/// Parses a value from the remaining input, knowing that no more input will follow.
+	pub fn finish(mut self) -> Result<T, ParseError> {
+		match self.parse(true)? {
+			Feed::Done(value) => Ok(value),
+			Feed::NeedMore => unreachable!("a final parse does not ask for more input")
+		}
+	}
+
+	fn parse(&mut self, last: bool) -> Result<Feed<T>, ParseError> {
+		let mut buffer = CharStream::new(self.buffer.clone()).build();
+		let end = self.buffer.trim_end().chars().count();
+
+		match T::parse(&mut buffer) {
+			Ok(_) if !last && buffer.is_at_end() => Ok(Feed::NeedMore),
+			Ok(value) => {
+				self.buffer = self.buffer.chars().skip(buffer.position().index()).collect();
+				Ok(Feed::Done(value))
+			}
+			Err(error) if !last && (error.kind() == ParseErrorKind::UnexpectedEof || error.position().index() >= end) => Ok(Feed::NeedMore),
+			Err(error) => {
+				self.buffer.clear();
+				Err(error)
+			}
+		}
+	}
+}
+
+impl<T> Default for Incremental<T> where T: Parse {
+	fn default() -> Self {
+		Self::new()
+	}
+}
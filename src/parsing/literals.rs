@@ -0,0 +1,1336 @@
+use std::{fmt, marker::PhantomData, net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr}, path::{Path, PathBuf}};
+
+use super::{charstream::{CharStream, Span, WhitespaceType}, tokens, LineEnd, List, Number, Parse, ParseError, StringValue, Terminators};
+
+/// Parses a [`Number`] that has exactly `digits` digits, and returns its value.
+fn fixed_number(value: &mut CharStream, digits: usize, name: &str) -> Result<(u32, Span), ParseError> {
+	let number = Number::parse(value)?;
+	if number.value.len() != digits {
+		return Err(ParseError::new(&format!("Expected {} to have {} digits.", name, digits), number.span.start));
+	}
+
+	match number.value.parse() {
+		Ok(result) => Ok((result, number.span)),
+		Err(_) => Err(ParseError::new(&format!("Could not read {}.", name), number.span.start))
+	}
+}
+
+/// Parses a number with `digits` digits, and checks that it is in the given range.
+fn ranged_number(value: &mut CharStream, digits: usize, name: &str, range: std::ops::RangeInclusive<u32>) -> Result<u32, ParseError> {
+	let (number, span) = fixed_number(value, digits, name)?;
+	if !range.contains(&number) {
+		return Err(ParseError::new(&format!("Expected {} to be between {} and {}, found {}.", name, range.start(), range.end(), number), span.start));
+	}
+
+	Ok(number)
+}
+
+/// Consumes the next character if it matches the predicate.
+fn next_char_if(value: &mut CharStream, predicate: impl Fn(char) -> bool) -> Option<char> {
+	let mut char_value = value.clone();
+	match char_value.next() {
+		Some(chr) if predicate(chr) => {
+			*value = char_value;
+			Some(chr)
+		}
+		_ => None
+	}
+}
+
+/// Consumes the next character if it is one of the expected characters.
+fn expect_char(value: &mut CharStream, expected: &[char]) -> Option<char> {
+	next_char_if(value, |chr| expected.contains(&chr))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+	match month {
+		2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31
+	}
+}
+
+/// A DateTime is an ISO-8601 timestamp, like `2024-01-02T03:04:05Z`.
+/// 
+/// The date and the time are both required, seconds can have a fraction (`05.123`), 
+/// and the timestamp can end in `Z` or an offset like `+01:00`. No whitespace is allowed inside the timestamp.
+/// Every component is checked to be in range, so a month has to be between 1 and 12, and the day has to exist in that month.
+/// 
+/// With the `chrono` feature, the timestamp can be converted to the types of the chrono crate.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::DateTime, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("2024-01-02T03:04:05Z".to_owned()).build();
+/// 	let value = DateTime::parse(&mut buffer).unwrap();
+/// 	assert_eq!(value.lexeme(), "2024-01-02T03:04:05Z");
+/// 	assert_eq!((value.year(), value.month(), value.day()), (2024, 1, 2));
+/// 	assert_eq!((value.hour(), value.minute(), value.second()), (3, 4, 5));
+/// 	assert_eq!(value.offset(), Some(0));
+/// 
+/// 	#[cfg(feature = "chrono")]
+/// 	assert_eq!(value.fixed_offset().unwrap().to_rfc3339(), "2024-01-02T03:04:05+00:00");
+/// 
+/// 	let mut buffer = CharStream::new("2024-13-02T03:04:05Z".to_owned()).build();
+/// 	let error = DateTime::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(error.position().column(), 5);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DateTime {
+	year: u32,
+	month: u32,
+	day: u32,
+	hour: u32,
+	minute: u32,
+	second: u32,
+	nanosecond: u32,
+	offset: Option<i32>,
+	lexeme: String,
+	span: Span
+}
+
+impl DateTime {
+	pub fn year(&self) -> u32 {
+		self.year
+	}
+
+	pub fn month(&self) -> u32 {
+		self.month
+	}
+
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+
+	pub fn hour(&self) -> u32 {
+		self.hour
+	}
+
+	pub fn minute(&self) -> u32 {
+		self.minute
+	}
+
+	pub fn second(&self) -> u32 {
+		self.second
+	}
+
+	pub fn nanosecond(&self) -> u32 {
+		self.nanosecond
+	}
+
+	/// The offset from UTC in seconds, or `None` if the timestamp is in local time.
+	pub fn offset(&self) -> Option<i32> {
+		self.offset
+	}
+
+	/// The timestamp exactly as it was written.
+	pub fn lexeme(&self) -> &str {
+		&self.lexeme
+	}
+
+	#[cfg(feature = "chrono")]
+	pub fn naive(&self) -> chrono::NaiveDateTime {
+		let date = chrono::NaiveDate::from_ymd_opt(self.year as i32, self.month, self.day)
+			.expect("date is validated while parsing");
+		let second = self.second.min(59);
+		let nanosecond = self.nanosecond + (self.second - second) * 1_000_000_000;
+		date.and_hms_nano_opt(self.hour, self.minute, second, nanosecond)
+			.expect("time is validated while parsing")
+	}
+
+	/// Returns the timestamp with its offset, or `None` if the timestamp is in local time.
+	#[cfg(feature = "chrono")]
+	pub fn fixed_offset(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+		let offset = chrono::FixedOffset::east_opt(self.offset?)?;
+		self.naive().and_local_timezone(offset).single()
+	}
+}
+
+impl Parse for DateTime {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut date_value = value.clone();
+		date_value.skip_whitespace();
+		let start = date_value.position();
+		date_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let year = fixed_number(&mut date_value, 4, "year")?.0;
+		tokens::Hyphen::parse(&mut date_value)?;
+		let month = ranged_number(&mut date_value, 2, "month", 1..=12)?;
+		tokens::Hyphen::parse(&mut date_value)?;
+		let day = ranged_number(&mut date_value, 2, "day", 1..=days_in_month(year, month))?;
+
+		if expect_char(&mut date_value, &['T', 't']).is_none() {
+			return Err(ParseError::new("Expected 'T' between the date and the time.", date_value.position()));
+		}
+
+		let hour = ranged_number(&mut date_value, 2, "hour", 0..=23)?;
+		tokens::Colon::parse(&mut date_value)?;
+		let minute = ranged_number(&mut date_value, 2, "minute", 0..=59)?;
+		tokens::Colon::parse(&mut date_value)?;
+		let second = ranged_number(&mut date_value, 2, "second", 0..=60)?;
+
+		let mut nanosecond = 0;
+		if expect_char(&mut date_value, &['.']).is_some() {
+			let fraction = Number::parse(&mut date_value)?;
+			if fraction.value.len() > 9 {
+				return Err(ParseError::new("Fractions of a second can have at most 9 digits.", fraction.span.start));
+			}
+			nanosecond = format!("{:0<9}", fraction.value).parse().unwrap_or(0);
+		}
+
+		let offset = match expect_char(&mut date_value, &['Z', 'z', '+', '-']) {
+			Some('Z' | 'z') => Some(0),
+			Some(sign) => {
+				let hours = ranged_number(&mut date_value, 2, "offset hour", 0..=23)?;
+				tokens::Colon::parse(&mut date_value)?;
+				let minutes = ranged_number(&mut date_value, 2, "offset minute", 0..=59)?;
+				let offset = (hours * 3600 + minutes * 60) as i32;
+				Some(if sign == '-' { -offset } else { offset })
+			}
+			None => None
+		};
+
+		let end = date_value.position();
+		value.goto(end.clone())?;
+
+		let mut lexeme_value = date_value.clone();
+		lexeme_value.goto(start.clone())?;
+		let mut lexeme = String::new();
+		while lexeme_value.position() < end {
+			match lexeme_value.next() {
+				Some(chr) => lexeme.push(chr),
+				None => break
+			}
+		}
+
+		Ok(Self { year, month, day, hour, minute, second, nanosecond, offset, lexeme, span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for DateTime {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "DateTime({}, from {})", self.lexeme, self.span)
+	}
+}
+
+/// Parses a dot separated list of identifiers made of ASCII letters, digits and hyphens, like `beta.1`.
+fn dotted_identifiers(value: &mut CharStream, name: &str) -> Result<Vec<String>, ParseError> {
+	let mut identifiers = Vec::new();
+
+	loop {
+		let start = value.position();
+		let mut identifier = String::new();
+		while let Some(chr) = next_char_if(value, |chr| chr.is_ascii_alphanumeric() || chr == '-') {
+			identifier.push(chr);
+		}
+
+		if identifier.is_empty() {
+			return Err(ParseError::new(&format!("Expected an identifier in the {}.", name), start));
+		}
+		identifiers.push(identifier);
+
+		if expect_char(value, &['.']).is_none() {
+			return Ok(identifiers);
+		}
+	}
+}
+
+/// A Version is a semantic version, like `1.2.3`, `1.2.3-beta.1` or `1.2.3+build.5`.
+/// 
+/// The minor and patch versions can be left out (`1` and `1.2`), they count as 0 when comparing versions.
+/// A pre-release or build can only follow a full version. No whitespace is allowed inside the version.
+/// Requirements on a version, like `^1.0`, are not versions themselves, they are parsed by [`VersionReq`].
+/// 
+/// Versions are ordered by the rules of semantic versioning, so `1.0.0-alpha < 1.0.0-alpha.1 < 1.0.0-beta < 1.0.0`,
+/// and the build metadata is ignored when comparing.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::Version, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("1.2.3".to_owned()).build();
+/// 	let version = Version::parse(&mut buffer).unwrap();
+/// 	assert_eq!((version.major(), version.minor(), version.patch()), (1, Some(2), Some(3)));
+/// 
+/// 	let mut buffer = CharStream::new("1.2.3-beta.1+exp.sha".to_owned()).build();
+/// 	let pre_release = Version::parse(&mut buffer).unwrap();
+/// 	assert_eq!(pre_release.pre_release(), ["beta", "1"]);
+/// 	assert_eq!(pre_release.build(), ["exp", "sha"]);
+/// 	assert!(pre_release < version);
+/// 
+/// 	let mut buffer = CharStream::new("1.2".to_owned()).build();
+/// 	let partial = Version::parse(&mut buffer).unwrap();
+/// 	assert_eq!(partial.patch(), None);
+/// 	assert!(partial < version);
+/// 
+/// 	let mut buffer = CharStream::new("1..2".to_owned()).build();
+/// 	assert!(Version::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Version {
+	major: u64,
+	minor: Option<u64>,
+	patch: Option<u64>,
+	pre_release: Vec<String>,
+	build: Vec<String>,
+	span: Span
+}
+
+impl Version {
+	pub fn major(&self) -> u64 {
+		self.major
+	}
+
+	pub fn minor(&self) -> Option<u64> {
+		self.minor
+	}
+
+	pub fn patch(&self) -> Option<u64> {
+		self.patch
+	}
+
+	/// The identifiers of the pre-release, empty if the version is not a pre-release.
+	pub fn pre_release(&self) -> &[String] {
+		&self.pre_release
+	}
+
+	/// The identifiers of the build metadata.
+	pub fn build(&self) -> &[String] {
+		&self.build
+	}
+
+	/// The major, minor and patch versions, where a missing one counts as 0.
+	fn numbers(&self) -> (u64, u64, u64) {
+		(self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0))
+	}
+
+	fn number(value: &mut CharStream, name: &str) -> Result<u64, ParseError> {
+		let number = Number::parse(value)?;
+		number.value.parse().map_err(|_| ParseError::new(&format!("The {} version is too large.", name), number.span.start))
+	}
+}
+
+impl Parse for Version {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut version_value = value.clone();
+		version_value.skip_whitespace();
+		let start = version_value.position();
+		version_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let major = Self::number(&mut version_value, "major")?;
+		let mut minor = None;
+		let mut patch = None;
+		if expect_char(&mut version_value, &['.']).is_some() {
+			minor = Some(Self::number(&mut version_value, "minor")?);
+
+			if expect_char(&mut version_value, &['.']).is_some() {
+				patch = Some(Self::number(&mut version_value, "patch")?);
+			}
+		}
+
+		let mut pre_release = Vec::new();
+		let mut build = Vec::new();
+		if patch.is_some() {
+			if expect_char(&mut version_value, &['-']).is_some() {
+				pre_release = dotted_identifiers(&mut version_value, "pre-release")?;
+			}
+
+			if expect_char(&mut version_value, &['+']).is_some() {
+				build = dotted_identifiers(&mut version_value, "build")?;
+			}
+		}
+
+		let end = version_value.position();
+		value.goto(end.clone())?;
+
+		Ok(Self { major, minor, patch, pre_release, build, span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl PartialEq for Version {
+	fn eq(&self, other: &Self) -> bool {
+		self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+	}
+}
+
+impl PartialOrd for Version {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		use std::cmp::Ordering;
+
+		let ordering = self.numbers().cmp(&other.numbers()).then_with(|| {
+			match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+				(true, true) => Ordering::Equal,
+				(true, false) => Ordering::Greater,
+				(false, true) => Ordering::Less,
+				(false, false) => {
+					for (left, right) in self.pre_release.iter().zip(&other.pre_release) {
+						let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+							(Ok(left), Ok(right)) => left.cmp(&right),
+							(Ok(_), Err(_)) => Ordering::Less,
+							(Err(_), Ok(_)) => Ordering::Greater,
+							(Err(_), Err(_)) => left.cmp(right)
+						};
+
+						if ordering != Ordering::Equal {
+							return ordering;
+						}
+					}
+
+					self.pre_release.len().cmp(&other.pre_release.len())
+				}
+			}
+		});
+
+		Some(ordering)
+	}
+}
+
+impl fmt::Display for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.major)?;
+		if let Some(minor) = self.minor {
+			write!(f, ".{}", minor)?;
+		}
+		if let Some(patch) = self.patch {
+			write!(f, ".{}", patch)?;
+		}
+		if !self.pre_release.is_empty() {
+			write!(f, "-{}", self.pre_release.join("."))?;
+		}
+		if !self.build.is_empty() {
+			write!(f, "+{}", self.build.join("."))?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Debug for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Version({}, from {})", self, self.span)
+	}
+}
+
+/// The operator of a [`Comparator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionOp {
+	Greater,
+	GreaterEqual,
+	Less,
+	LessEqual,
+	Equal,
+	/// `^`, allows any later version that does not change the leftmost number that is not 0.
+	Caret,
+	/// `~`, allows any later version with the same major and minor version, or the same major version when only that is given.
+	Tilde
+}
+
+/// A Comparator is a [`Version`] with an operator in front of it, like `>=1.2`.
+/// The operator is one of `>`, `>=`, `<`, `<=`, `=`, `==`, `^` or `~`, a version without an operator has to be equal.
+/// 
+/// `^` and `~` follow semantic versioning: `^1.2` is `>=1.2.0, <2.0.0`, `^0.2` is `>=0.2.0, <0.3.0`,
+/// `^0.0.3` is `>=0.0.3, <0.0.4`, and `~1.2` is `>=1.2.0, <1.3.0`.
+#[derive(Clone)]
+pub struct Comparator {
+	op: VersionOp,
+	version: Version,
+	span: Span
+}
+
+impl Comparator {
+	pub fn op(&self) -> VersionOp {
+		self.op
+	}
+
+	pub fn version(&self) -> &Version {
+		&self.version
+	}
+
+	/// Returns true when `version` meets this requirement.
+	pub fn matches(&self, version: &Version) -> bool {
+		match self.op {
+			VersionOp::Greater => version > &self.version,
+			VersionOp::GreaterEqual => version >= &self.version,
+			VersionOp::Less => version < &self.version,
+			VersionOp::LessEqual => version <= &self.version,
+			VersionOp::Equal => version == &self.version,
+			VersionOp::Caret | VersionOp::Tilde => version >= &self.version && version.numbers() < self.upper_bound()
+		}
+	}
+
+	/// The first major, minor and patch version that a `^` or `~` requirement does not allow anymore.
+	fn upper_bound(&self) -> (u64, u64, u64) {
+		let Version { major, minor, patch, .. } = self.version;
+		match (self.op, minor, patch) {
+			(VersionOp::Tilde, Some(minor), _) => (major, minor.saturating_add(1), 0),
+			(VersionOp::Tilde, None, _) => (major.saturating_add(1), 0, 0),
+			(_, Some(0), Some(patch)) if major == 0 => (0, 0, patch.saturating_add(1)),
+			(_, Some(minor), _) if major == 0 => (0, minor.saturating_add(1), 0),
+			_ => (major.saturating_add(1), 0, 0)
+		}
+	}
+
+	/// Parses the token `T` if it is next.
+	fn accept<T>(value: &mut CharStream) -> bool where T: tokens::Token {
+		let mut token_value = value.clone();
+		match T::parse(&mut token_value) {
+			Ok(_) => {
+				*value = token_value;
+				true
+			}
+			Err(_) => false
+		}
+	}
+}
+
+impl Parse for Comparator {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut start_value = value.clone();
+		start_value.skip_whitespace();
+		let start = start_value.position();
+
+		let op = if Self::accept::<tokens::GreaterEqual>(value) {
+			VersionOp::GreaterEqual
+		} else if Self::accept::<tokens::LessEqual>(value) {
+			VersionOp::LessEqual
+		} else if Self::accept::<tokens::Greater>(value) {
+			VersionOp::Greater
+		} else if Self::accept::<tokens::Less>(value) {
+			VersionOp::Less
+		} else if Self::accept::<tokens::Punct<'^'>>(value) {
+			VersionOp::Caret
+		} else if Self::accept::<tokens::Punct<'~'>>(value) {
+			VersionOp::Tilde
+		} else {
+			let _ = Self::accept::<tokens::EqualEqual>(value) || Self::accept::<tokens::Equal>(value);
+			VersionOp::Equal
+		};
+
+		let version = Version::parse(value)?;
+		let span = Span::new(start, version.span.end.clone());
+		Ok(Self { op, version, span })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Comparator {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Comparator({:?} {}, from {})", self.op, self.version, self.span)
+	}
+}
+
+/// A VersionReq is a list of requirements on a [`Version`], separated by commas, like `>=1.2, <2.0`.
+/// A version matches the requirement when it meets every [`Comparator`] in it.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::{Version, VersionReq}, Parse};
+/// # fn main() {
+/// 	let version = |text: &str| Version::parse(&mut CharStream::new(text.to_owned()).build()).unwrap();
+/// 
+/// 	let mut buffer = CharStream::new(">=1.2, <2.0".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert_eq!(requirement.comparators().count(), 2);
+/// 	assert!(requirement.matches(&version("1.5")));
+/// 	assert!(requirement.matches(&version("1.2.0")));
+/// 	assert!(!requirement.matches(&version("2.1")));
+/// 
+/// 	let mut buffer = CharStream::new("=1.0.0".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("1")));
+/// 	assert!(!requirement.matches(&version("1.0.1")));
+/// 
+/// 	let mut buffer = CharStream::new("^1.0".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("1.0.0")));
+/// 	assert!(requirement.matches(&version("1.9.3")));
+/// 	assert!(!requirement.matches(&version("0.9.0")));
+/// 	assert!(!requirement.matches(&version("2.0.0")));
+/// 
+/// 	let mut buffer = CharStream::new("^0.2.3".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("0.2.9")));
+/// 	assert!(!requirement.matches(&version("0.3.0")));
+/// 
+/// 	let mut buffer = CharStream::new("^0.0.3".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("0.0.3")));
+/// 	assert!(!requirement.matches(&version("0.0.4")));
+/// 
+/// 	let mut buffer = CharStream::new("~1.2.3".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("1.2.7")));
+/// 	assert!(!requirement.matches(&version("1.3.0")));
+/// 
+/// 	let mut buffer = CharStream::new("~1".to_owned()).build();
+/// 	let requirement = VersionReq::parse(&mut buffer).unwrap();
+/// 	assert!(requirement.matches(&version("1.8")));
+/// 	assert!(!requirement.matches(&version("2.0")));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct VersionReq {
+	comparators: List<Comparator, tokens::Comma>
+}
+
+impl VersionReq {
+	pub fn comparators(&self) -> impl Iterator<Item = &Comparator> {
+		self.comparators.items()
+	}
+
+	/// Returns true when `version` meets every requirement.
+	pub fn matches(&self, version: &Version) -> bool {
+		self.comparators().all(|comparator| comparator.matches(version))
+	}
+}
+
+impl Parse for VersionReq {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let comparators = List::parse(value)?;
+		if comparators.items().next().is_none() {
+			return Err(ParseError::new("Expected at least one version requirement.", comparators.span().start));
+		}
+
+		Ok(Self { comparators })
+	}
+
+	fn span(&self) -> Span {
+		self.comparators.span()
+	}
+}
+
+impl fmt::Debug for VersionReq {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "VersionReq({:#?})", self.comparators)
+	}
+}
+
+/// A HexColor is a color written as hex digits after a `#`, like `#1a2b3c`.
+/// 
+/// The 3 digit shorthand (`#abc` is `#aabbcc`), the 6 digit form, and an 8 digit form with an alpha channel are supported.
+/// The alpha is 255 when it is not given.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::HexColor, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("#fff".to_owned()).build();
+/// 	let color = HexColor::parse(&mut buffer).unwrap();
+/// 	assert_eq!(color.rgb(), (255, 255, 255));
+/// 
+/// 	let mut buffer = CharStream::new("#112233".to_owned()).build();
+/// 	let color = HexColor::parse(&mut buffer).unwrap();
+/// 	assert_eq!(color.rgb(), (0x11, 0x22, 0x33));
+/// 	assert_eq!(color.alpha(), 255);
+/// 
+/// 	let mut buffer = CharStream::new("#11223380".to_owned()).build();
+/// 	let color = HexColor::parse(&mut buffer).unwrap();
+/// 	assert_eq!(color.rgba(), (0x11, 0x22, 0x33, 0x80));
+/// 
+/// 	let mut buffer = CharStream::new("#gggggg".to_owned()).build();
+/// 	assert!(HexColor::parse(&mut buffer).is_err());
+/// 
+/// 	let mut buffer = CharStream::new("#12345".to_owned()).build();
+/// 	assert!(HexColor::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HexColor {
+	red: u8,
+	green: u8,
+	blue: u8,
+	alpha: u8,
+	span: Span
+}
+
+impl HexColor {
+	pub fn rgb(&self) -> (u8, u8, u8) {
+		(self.red, self.green, self.blue)
+	}
+
+	pub fn rgba(&self) -> (u8, u8, u8, u8) {
+		(self.red, self.green, self.blue, self.alpha)
+	}
+
+	pub fn alpha(&self) -> u8 {
+		self.alpha
+	}
+}
+
+impl Parse for HexColor {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut color_value = value.clone();
+		let hash = tokens::Hash::parse(&mut color_value)?;
+		color_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut digits = Vec::new();
+		loop {
+			let position = color_value.position();
+			match next_char_if(&mut color_value, |chr| chr.is_alphanumeric()) {
+				Some(chr) => match chr.to_digit(16) {
+					Some(digit) => digits.push(digit as u8),
+					None => return Err(ParseError::new(&format!("'{}' is not a hex digit.", chr), position))
+				}
+				None => break
+			}
+		}
+
+		let channel = |index: usize| digits[index] * 16 + digits[index + 1];
+		let (red, green, blue, alpha) = match digits.len() {
+			3 => (digits[0] * 17, digits[1] * 17, digits[2] * 17, 255),
+			6 => (channel(0), channel(2), channel(4), 255),
+			8 => (channel(0), channel(2), channel(4), channel(6)),
+			len => return Err(ParseError::new(&format!("A hex color has 3, 6 or 8 digits, found {}.", len), hash.span().end))
+		};
+
+		let end = color_value.position();
+		value.goto(end.clone())?;
+
+		let span = Span::new(hash.span().start, end);
+		Ok(Self { red, green, blue, alpha, span })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for HexColor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "HexColor(#{:02x}{:02x}{:02x}{:02x}, from {})", self.red, self.green, self.blue, self.alpha, self.span)
+	}
+}
+
+/// Parses the four octets of an IPv4 address, like `127.0.0.1`.
+fn ipv4(value: &mut CharStream) -> Result<Ipv4Addr, ParseError> {
+	let mut octets = [0; 4];
+	for (index, octet) in octets.iter_mut().enumerate() {
+		if index > 0 && expect_char(value, &['.']).is_none() {
+			return Err(ParseError::new("Expected '.' between the octets of the address.", value.position()));
+		}
+
+		let number = Number::parse(value)?;
+		*octet = match number.value.parse::<u8>() {
+			Ok(octet) if number.value.len() <= 3 => octet,
+			_ => return Err(ParseError::new(&format!("Expected an octet to be between 0 and 255, found {}.", number.value), number.span.start))
+		};
+	}
+
+	Ok(Ipv4Addr::from(octets))
+}
+
+/// Parses an IPv6 address, like `::1` or `2001:db8::ff00:42:8329`.
+fn ipv6(value: &mut CharStream) -> Result<Ipv6Addr, ParseError> {
+	let start = value.position();
+	let mut address = String::new();
+	while let Some(chr) = next_char_if(value, |chr| chr.is_ascii_hexdigit() || chr == ':' || chr == '.') {
+		address.push(chr);
+	}
+
+	address.parse().map_err(|_| ParseError::new(&format!("'{}' is not a valid IPv6 address.", address), start))
+}
+
+/// An IpAddress is an IPv4 address, like `127.0.0.1`, or an IPv6 address, like `::1`.
+/// 
+/// Every octet of an IPv4 address has to be between 0 and 255. No whitespace is allowed inside the address.
+/// ```
+/// # use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// # use parseal::parsing::{charstream::CharStream, literals::IpAddress, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("192.168.0.1".to_owned()).build();
+/// 	let address = IpAddress::parse(&mut buffer).unwrap();
+/// 	assert_eq!(address.addr(), IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+/// 
+/// 	let mut buffer = CharStream::new("::1".to_owned()).build();
+/// 	let address = IpAddress::parse(&mut buffer).unwrap();
+/// 	assert_eq!(address.addr(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+/// 
+/// 	let mut buffer = CharStream::new("192.168.256.1".to_owned()).build();
+/// 	let error = IpAddress::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:8:Error: 'Expected an octet to be between 0 and 255, found 256.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct IpAddress {
+	addr: IpAddr,
+	span: Span
+}
+
+impl IpAddress {
+	pub fn addr(&self) -> IpAddr {
+		self.addr
+	}
+
+	/// Parses an IPv4 address if the input starts with digits and a `.`, and an IPv6 address otherwise.
+	fn address(value: &mut CharStream) -> Result<IpAddr, ParseError> {
+		let mut digits_value = value.clone();
+		while next_char_if(&mut digits_value, |chr| chr.is_ascii_digit()).is_some() {}
+
+		match digits_value.position() > value.position() && expect_char(&mut digits_value, &['.']).is_some() {
+			true => Ok(IpAddr::V4(ipv4(value)?)),
+			false => Ok(IpAddr::V6(ipv6(value)?))
+		}
+	}
+}
+
+impl Parse for IpAddress {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut address_value = value.clone();
+		address_value.skip_whitespace();
+		let start = address_value.position();
+		address_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let addr = Self::address(&mut address_value)?;
+
+		let end = address_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { addr, span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for IpAddress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "IpAddress({}, from {})", self.addr, self.span)
+	}
+}
+
+/// A SocketAddress is an [`IpAddress`] with a port, like `127.0.0.1:8080`.
+/// An IPv6 address has to be between brackets, like `[::1]:443`. The port has to be between 0 and 65535.
+/// ```
+/// # use std::net::SocketAddr;
+/// # use parseal::parsing::{charstream::CharStream, literals::SocketAddress, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("127.0.0.1:8080".to_owned()).build();
+/// 	let address = SocketAddress::parse(&mut buffer).unwrap();
+/// 	assert_eq!(address.addr(), "127.0.0.1:8080".parse::<SocketAddr>().unwrap());
+/// 
+/// 	let mut buffer = CharStream::new("[::1]:443".to_owned()).build();
+/// 	let address = SocketAddress::parse(&mut buffer).unwrap();
+/// 	assert_eq!(address.addr().port(), 443);
+/// 	assert!(address.addr().is_ipv6());
+/// 
+/// 	let mut buffer = CharStream::new("127.0.0.1:70000".to_owned()).build();
+/// 	let error = SocketAddress::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:10:Error: 'Expected the port to be between 0 and 65535, found 70000.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SocketAddress {
+	addr: SocketAddr,
+	span: Span
+}
+
+impl SocketAddress {
+	pub fn addr(&self) -> SocketAddr {
+		self.addr
+	}
+}
+
+impl Parse for SocketAddress {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut address_value = value.clone();
+		address_value.skip_whitespace();
+		let start = address_value.position();
+		address_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let ip = match expect_char(&mut address_value, &['[']) {
+			Some(_) => {
+				let ip = ipv6(&mut address_value)?;
+				if expect_char(&mut address_value, &[']']).is_none() {
+					return Err(ParseError::new("Expected ']' after the IPv6 address.", address_value.position()));
+				}
+				IpAddr::V6(ip)
+			}
+			None => IpAddr::V4(ipv4(&mut address_value)?)
+		};
+
+		tokens::Colon::parse(&mut address_value)?;
+		let port = Number::parse(&mut address_value)?;
+		let port = match port.value.parse::<u16>() {
+			Ok(number) => number,
+			Err(_) => return Err(ParseError::new(&format!("Expected the port to be between 0 and 65535, found {}.", port.value), port.span.start))
+		};
+
+		let end = address_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { addr: SocketAddr::new(ip, port), span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for SocketAddress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "SocketAddress({}, from {})", self.addr, self.span)
+	}
+}
+
+/// A Duration is a human readable length of time, like `90s`, `5m` or `1h30m`.
+/// 
+/// Every part is a whole number followed by a unit: `ms`, `s`, `m`, `h` or `d`. 
+/// The parts of a compound duration are added together, and no whitespace is allowed between them.
+/// ```
+/// # use std::time;
+/// # use parseal::parsing::{charstream::CharStream, literals::Duration, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("90s".to_owned()).build();
+/// 	assert_eq!(Duration::parse(&mut buffer).unwrap().duration(), time::Duration::from_secs(90));
+/// 
+/// 	let mut buffer = CharStream::new("1h30m".to_owned()).build();
+/// 	assert_eq!(Duration::parse(&mut buffer).unwrap().duration(), time::Duration::from_secs(5400));
+/// 
+/// 	let mut buffer = CharStream::new("250ms".to_owned()).build();
+/// 	assert_eq!(Duration::parse(&mut buffer).unwrap().duration(), time::Duration::from_millis(250));
+/// 
+/// 	let mut buffer = CharStream::new("10y".to_owned()).build();
+/// 	let error = Duration::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:2:Error: 'Unknown unit 'y', expected one of: ms, s, m, h, d.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Duration {
+	duration: std::time::Duration,
+	span: Span
+}
+
+impl Duration {
+	pub fn duration(&self) -> std::time::Duration {
+		self.duration
+	}
+
+	/// Parses a single number with its unit.
+	fn part(value: &mut CharStream) -> Result<std::time::Duration, ParseError> {
+		let number = Number::parse(value)?;
+		let amount: u64 = number.value.parse()
+			.map_err(|_| ParseError::new("The duration is too large.", number.span.start.clone()))?;
+
+		let unit_start = value.position();
+		let mut unit = String::new();
+		while let Some(chr) = next_char_if(value, |chr| chr.is_alphabetic()) {
+			unit.push(chr);
+		}
+
+		let seconds = match unit.as_str() {
+			"ms" => return Ok(std::time::Duration::from_millis(amount)),
+			"s" => 1,
+			"m" => 60,
+			"h" => 60 * 60,
+			"d" => 24 * 60 * 60,
+			_ => return Err(ParseError::new(&format!("Unknown unit '{}', expected one of: ms, s, m, h, d.", unit), unit_start))
+		};
+
+		match amount.checked_mul(seconds) {
+			Some(seconds) => Ok(std::time::Duration::from_secs(seconds)),
+			None => Err(ParseError::new("The duration is too large.", number.span.start))
+		}
+	}
+}
+
+impl Parse for Duration {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut duration_value = value.clone();
+		duration_value.skip_whitespace();
+		let start = duration_value.position();
+		duration_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut duration = Self::part(&mut duration_value)?;
+		while next_char_if(&mut duration_value.clone(), |chr| chr.is_ascii_digit()).is_some() {
+			let part_start = duration_value.position();
+			duration = duration.checked_add(Self::part(&mut duration_value)?)
+				.ok_or_else(|| ParseError::new("The duration is too large.", part_start))?;
+		}
+
+		let end = duration_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { duration, span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Duration {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Duration({:?}, from {})", self.duration, self.span)
+	}
+}
+
+/// A Quantity is a number with an optional unit, like `50%`, `10px` or `1.5rem`.
+/// 
+/// The number can have a fractional part. The unit is either `%` or a run of letters, and any unit is accepted.
+/// No whitespace is allowed between the number and its unit, so `10 px` is the number `10` without a unit.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::Quantity, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("50%".to_owned()).build();
+/// 	let quantity = Quantity::parse(&mut buffer).unwrap();
+/// 	assert_eq!(quantity.value(), 50.0);
+/// 	assert_eq!(quantity.unit(), Some("%"));
+/// 
+/// 	let mut buffer = CharStream::new("10px".to_owned()).build();
+/// 	let quantity = Quantity::parse(&mut buffer).unwrap();
+/// 	assert_eq!(quantity.value(), 10.0);
+/// 	assert_eq!(quantity.unit(), Some("px"));
+/// 
+/// 	let mut buffer = CharStream::new("1.5rem".to_owned()).build();
+/// 	let quantity = Quantity::parse(&mut buffer).unwrap();
+/// 	assert_eq!(quantity.value(), 1.5);
+/// 	assert_eq!(quantity.number(), "1.5");
+/// 	assert_eq!(quantity.unit(), Some("rem"));
+/// 	assert_eq!(quantity.span().end.column(), 6);
+/// 
+/// 	let mut buffer = CharStream::new("10 px".to_owned()).build();
+/// 	assert_eq!(Quantity::parse(&mut buffer).unwrap().unit(), None);
+/// 
+/// 	let mut buffer = CharStream::new("1.em".to_owned()).build();
+/// 	let error = Quantity::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:2:Error: 'Expected digits after the decimal point.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Quantity {
+	number: String,
+	unit: Option<String>,
+	span: Span
+}
+
+impl Quantity {
+	/// Returns the value of the number, without its unit.
+	pub fn value(&self) -> f64 {
+		self.number.parse().unwrap_or(f64::NAN)
+	}
+
+	/// Returns the number as it was written.
+	pub fn number(&self) -> &str {
+		&self.number
+	}
+
+	pub fn unit(&self) -> Option<&str> {
+		self.unit.as_deref()
+	}
+}
+
+impl Parse for Quantity {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut quantity_value = value.clone();
+		quantity_value.skip_whitespace();
+		let start = quantity_value.position();
+		quantity_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut number = Number::parse(&mut quantity_value)?.value;
+		if expect_char(&mut quantity_value, &['.']).is_some() {
+			let fraction = Number::parse(&mut quantity_value)
+				.map_err(|error| ParseError::new("Expected digits after the decimal point.", error.position))?;
+			number.push('.');
+			number.push_str(&fraction.value);
+		}
+
+		let mut unit = String::new();
+		match expect_char(&mut quantity_value, &['%']) {
+			Some(percent) => unit.push(percent),
+			None => while let Some(chr) = next_char_if(&mut quantity_value, |chr| chr.is_alphabetic()) {
+				unit.push(chr);
+			}
+		}
+
+		let end = quantity_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { number, unit: Some(unit).filter(|unit| !unit.is_empty()), span: Span::new(start, end) })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl fmt::Debug for Quantity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Quantity({}{}, from {})", self.number, self.unit().unwrap_or_default(), self.span)
+	}
+}
+
+/// A PathLiteral is a file path, like `./src/main.rs` or `C:\foo\bar`.
+/// 
+/// A bare path is a run of letters, digits, `/`, `\`, `.`, `-` and `_`, that can start with a drive, like `C:`.
+/// It ends at whitespace, at any other character, or at one of the terminators of `T`.
+/// A path with other characters, like spaces, can be written as a [`StringValue`], like `"My Documents/a.txt"`.
+/// ```
+/// # use std::path::Path;
+/// # use parseal::parsing::{charstream::CharStream, literals::PathLiteral, LineEnd, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("./src/main.rs --release".to_owned()).build();
+/// 	let path = PathLiteral::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(path.path(), Path::new("./src/main.rs"));
+/// 	assert_eq!(path.span().end.column(), 13);
+/// 
+/// 	let mut buffer = CharStream::new("C:\\foo\\bar".to_owned()).build();
+/// 	let path = PathLiteral::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(path.path(), Path::new("C:\\foo\\bar"));
+/// 
+/// 	let mut buffer = CharStream::new("\"My Documents/a.txt\"".to_owned()).build();
+/// 	let path = PathLiteral::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(path.path(), Path::new("My Documents/a.txt"));
+/// 
+/// 	let mut buffer = CharStream::new("= a".to_owned()).build();
+/// 	let error = PathLiteral::<LineEnd>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:0:Error: 'Expected a path.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PathLiteral<T = LineEnd> where T: Terminators {
+	path: PathBuf,
+	span: Span,
+	terminators: PhantomData<T>
+}
+
+impl<T> PathLiteral<T> where T: Terminators {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	fn is_path_char(chr: char) -> bool {
+		(chr.is_alphanumeric() || ['/', '\\', '.', '-', '_'].contains(&chr)) && !T::TERMINATORS.contains(&chr)
+	}
+}
+
+impl<T> Parse for PathLiteral<T> where T: Terminators {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		let mut path_value = value.clone();
+		path_value.skip_whitespace();
+		if let Some('"') = path_value.clone().next() {
+			let string = StringValue::parse(value)?;
+			return Ok(Self { path: PathBuf::from(string.value()), span: string.span(), terminators: PhantomData });
+		}
+
+		value.count_node()?;
+		let start = path_value.position();
+		path_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut path = String::new();
+		let mut drive_value = path_value.clone();
+		if let Some(drive) = next_char_if(&mut drive_value, |chr| chr.is_ascii_alphabetic()) {
+			if expect_char(&mut drive_value, &[':']).is_some() && !T::TERMINATORS.contains(&':') {
+				path.push(drive);
+				path.push(':');
+				path_value = drive_value;
+			}
+		}
+
+		while let Some(chr) = next_char_if(&mut path_value, Self::is_path_char) {
+			path.push(chr);
+		}
+
+		if path.is_empty() {
+			return Err(ParseError::new("Expected a path.", start));
+		}
+
+		let end = path_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { path: PathBuf::from(path), span: Span::new(start, end), terminators: PhantomData })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<T> fmt::Debug for PathLiteral<T> where T: Terminators {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "PathLiteral({}, from {})", self.path.display(), self.span)
+	}
+}
+
+/// A Glob is a file pattern, like `*.rs` or `src/**/[a-z]*.txt`.
+/// 
+/// A glob is a run of letters, digits, `*`, `?`, `/`, `.`, `-`, `_` and character classes like `[a-z]`.
+/// It ends at whitespace, at any other character, or at one of the terminators of `T`.
+/// Every `[` has to be closed by a `]` on the same line, and a class can not be empty.
+/// 
+/// [`Glob::matches`] checks a path against the pattern: `*` matches anything except `/`, `**` matches anything,
+/// `?` matches one character except `/`, and a class can be negated with `!` or `^`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, literals::Glob, LineEnd, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("*.rs src".to_owned()).build();
+/// 	let glob = Glob::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(glob.pattern(), "*.rs");
+/// 	assert!(glob.matches("main.rs"));
+/// 	assert!(!glob.matches("src/main.rs"));
+/// 
+/// 	let mut buffer = CharStream::new("[a-z]*.txt".to_owned()).build();
+/// 	let glob = Glob::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert!(glob.matches("notes.txt"));
+/// 	assert!(!glob.matches("Notes.txt"));
+/// 
+/// 	let mut buffer = CharStream::new("src/**/*.txt".to_owned()).build();
+/// 	let glob = Glob::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert!(glob.matches("src/a.txt"));
+/// 	assert!(glob.matches("src/a/b/c.txt"));
+/// 
+/// 	let mut buffer = CharStream::new("*a*a*a*a*a*a*a*a*b".to_owned()).build();
+/// 	let glob = Glob::<LineEnd>::parse(&mut buffer).unwrap();
+/// 	assert!(!glob.matches(&"a".repeat(200)));
+/// 
+/// 	let mut buffer = CharStream::new("*.[ch".to_owned()).build();
+/// 	let error = Glob::<LineEnd>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:2:Error: 'Expected a ']' to close the character class.'");
+/// 
+/// 	let mut buffer = CharStream::new("a]".to_owned()).build();
+/// 	let error = Glob::<LineEnd>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:1:Error: 'Unexpected ']' outside of a character class.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Glob<T = LineEnd> where T: Terminators {
+	pattern: String,
+	span: Span,
+	terminators: PhantomData<T>
+}
+
+impl<T> Glob<T> where T: Terminators {
+	pub fn pattern(&self) -> &str {
+		&self.pattern
+	}
+
+	/// Returns true if the whole of `path` matches the pattern.
+	/// Every combination of a place in the pattern and a place in the path is only tried once, 
+	/// so patterns with many stars do not take exponential time.
+	pub fn matches(&self, path: &str) -> bool {
+		let pattern = self.pattern.chars().collect::<Vec<_>>();
+		let path = path.chars().collect::<Vec<_>>();
+		let mut memo = vec![None; (pattern.len() + 1) * (path.len() + 1)];
+		Self::match_at(&pattern, &path, 0, 0, &mut memo)
+	}
+
+	/// Returns true if `pattern` from `p` matches `path` from `t`, the results are kept in `memo`.
+	fn match_at(pattern: &[char], path: &[char], p: usize, t: usize, memo: &mut [Option<bool>]) -> bool {
+		let key = p * (path.len() + 1) + t;
+		if let Some(result) = memo[key] {
+			return result;
+		}
+
+		let rest = &path[t..];
+		let result = match &pattern[p..] {
+			[] => rest.is_empty(),
+			['*', '*', '/', ..] => {
+				Self::match_at(pattern, path, p + 3, t, memo) || 
+				(t..path.len()).any(|index| path[index] == '/' && Self::match_at(pattern, path, p + 3, index + 1, memo))
+			}
+			['*', '*', ..] => (t..=path.len()).any(|index| Self::match_at(pattern, path, p + 2, index, memo)),
+			['*', ..] => {
+				let segment = rest.iter().position(|chr| *chr == '/').map_or(path.len(), |index| t + index);
+				(t..=segment).any(|index| Self::match_at(pattern, path, p + 1, index, memo))
+			}
+			['?', ..] => matches!(rest.first(), Some(chr) if *chr != '/') && Self::match_at(pattern, path, p + 1, t + 1, memo),
+			['[', class @ ..] => match class.iter().position(|chr| *chr == ']') {
+				Some(close) => {
+					matches!(rest.first(), Some(chr) if *chr != '/' && Self::match_class(&class[..close], *chr)) && 
+					Self::match_at(pattern, path, p + close + 2, t + 1, memo)
+				}
+				None => false
+			},
+			[chr, ..] => rest.first() == Some(chr) && Self::match_at(pattern, path, p + 1, t + 1, memo)
+		};
+
+		memo[key] = Some(result);
+		result
+	}
+
+	fn match_class(class: &[char], chr: char) -> bool {
+		let (negated, class) = match class {
+			['!' | '^', rest @ ..] if !rest.is_empty() => (true, rest),
+			class => (false, class)
+		};
+
+		let mut index = 0;
+		let mut found = false;
+		while index < class.len() {
+			if index + 2 < class.len() && class[index + 1] == '-' {
+				found |= (class[index]..=class[index + 2]).contains(&chr);
+				index += 3;
+			} else {
+				found |= class[index] == chr;
+				index += 1;
+			}
+		}
+		found != negated
+	}
+
+	fn is_glob_char(chr: char) -> bool {
+		(chr.is_alphanumeric() || ['*', '?', '/', '.', '-', '_', '[', ']'].contains(&chr)) && !T::TERMINATORS.contains(&chr)
+	}
+}
+
+impl<T> Parse for Glob<T> where T: Terminators {
+	fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+		value.count_node()?;
+		let mut glob_value = value.clone();
+		glob_value.skip_whitespace();
+		let start = glob_value.position();
+		glob_value.set_whitespace(WhitespaceType::KeepAll);
+
+		let mut pattern = String::new();
+		loop {
+			let position = glob_value.position();
+			match next_char_if(&mut glob_value, Self::is_glob_char) {
+				Some('[') => {
+					pattern.push('[');
+					let mut class = String::new();
+					loop {
+						match glob_value.next() {
+							Some(']') => break,
+							Some('\n') | None => return Err(ParseError::new("Expected a ']' to close the character class.", position)),
+							Some(chr) => class.push(chr)
+						}
+					}
+
+					if class.is_empty() {
+						return Err(ParseError::new("A character class can not be empty.", position));
+					}
+					pattern.push_str(&class);
+					pattern.push(']');
+				}
+				Some(']') => return Err(ParseError::new("Unexpected ']' outside of a character class.", position)),
+				Some(chr) => pattern.push(chr),
+				None => break
+			}
+		}
+
+		if pattern.is_empty() {
+			return Err(ParseError::new("Expected a glob pattern.", start));
+		}
+
+		let end = glob_value.position();
+		value.goto(end.clone())?;
+		Ok(Self { pattern, span: Span::new(start, end), terminators: PhantomData })
+	}
+
+	fn span(&self) -> Span {
+		self.span.clone()
+	}
+}
+
+impl<T> fmt::Debug for Glob<T> where T: Terminators {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Glob({}, from {})", self.pattern, self.span)
+	}
+}
@@ -1,11 +1,71 @@
 use std::fmt;
 
-use super::{Parse, ParseError, charstream::CharStream};
-
+use super::{Parse, ParseError, charstream::{CharStream, WhitespaceType}};
+
+/// A Token is a fixed piece of punctuation, like `,` or `==`.
+/// Every token stores the [`super::Span`] it was matched at, leading whitespace is not part of that span.
+/// 
+/// When the input ends before the token could be completed, the error has the kind [`super::ParseErrorKind::UnexpectedEof`],
+/// which tells an incremental parser that more input is needed, rather than that the input is wrong.
+/// ```
+/// # use parseal::parsing::{charstream::{CharStream, WhitespaceType}, tokens::{Comma, EqualEqual}, Number, Parse, ParseErrorKind};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("1 , 2".to_owned()).build();
+/// 	let _ = Number::parse(&mut buffer).unwrap();
+/// 
+/// 	let comma = Comma::parse(&mut buffer).unwrap();
+/// 	assert_eq!(comma.span().start.column(), 2);
+/// 	assert_eq!(comma.span().end.column(), 3);
+/// 
+/// 	let mut buffer = CharStream::new("=".to_owned()).build();
+/// 	let error = EqualEqual::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(error.kind(), ParseErrorKind::UnexpectedEof);
+/// 
+/// 	let mut buffer = CharStream::new("".to_owned()).build();
+/// 	let error = Comma::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(error.kind(), ParseErrorKind::UnexpectedEof);
+/// 
+/// 	let mut buffer = CharStream::new("=;".to_owned()).build();
+/// 	let error = EqualEqual::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(error.kind(), ParseErrorKind::Mismatch);
+/// 
+/// 	// whitespace is only skipped before a token when the stream skips whitespace, and never inside of it.
+/// 	let mut buffer = CharStream::new("= =".to_owned()).build();
+/// 	assert!(EqualEqual::parse(&mut buffer).is_err());
+/// 
+/// 	let mut buffer = CharStream::new("1\n, 2".to_owned()).whitespace(WhitespaceType::KeepAll).build();
+/// 	let _ = Number::parse(&mut buffer).unwrap();
+/// 	assert!(Comma::parse(&mut buffer).is_err());
+/// # }
+/// ```
 pub trait Token: Parse + fmt::Display {
 
 }
 
+/// A Delimiter is a pair of tokens that surround a value, like `(` and `)`.
+/// Both sides can be longer than a single character, like `{{` and `}}`.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens::{self, Delimiter}, Group, Identifier, Number, Parse};
+/// # fn main() {
+/// 	assert_eq!(tokens::Brace::open(), "{");
+/// 	assert_eq!(tokens::Brace::close(), "}");
+/// 
+/// 	let mut buffer = CharStream::new("{ 12 ]".to_owned()).build();
+/// 	let error = Group::<tokens::Brace, Number>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:5:Error: 'Expected '}' to close the '{' from 0:0.'");
+/// 
+/// 	let mut buffer = CharStream::new("{{ inner }}".to_owned()).build();
+/// 	let group = Group::<tokens::DoubleBrace, Identifier>::parse(&mut buffer).unwrap();
+/// 	assert!(*group.item() == "inner");
+/// 	assert_eq!(group.span().end.column(), 11);
+/// 
+/// 	let mut buffer = CharStream::new("{ { inner } }".to_owned()).build();
+/// 	assert!(Group::<tokens::DoubleBrace, Identifier>::parse(&mut buffer).is_err());
+/// 
+/// 	let mut buffer = CharStream::new("<% inner %>".to_owned()).build();
+/// 	assert!(Group::<tokens::TemplateTag, Identifier>::parse(&mut buffer).is_ok());
+/// # }
+/// ```
 pub trait Delimiter: Clone {
 	type Start: Token;
 	type End: Token;
@@ -13,6 +73,10 @@ pub trait Delimiter: Clone {
 	fn new(start: Self::Start, end: Self::End) -> Self where Self: Sized;
     fn span(&self) -> super::Span;
     fn name() -> String;
+    /// The text of the opening delimiter.
+    fn open() -> &'static str;
+    /// The text of the closing delimiter.
+    fn close() -> &'static str;
 }
 
 macro_rules! create_tokens {
@@ -27,14 +91,20 @@ macro_rules! create_tokens {
             
             impl Parse for $id {
                 fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+                    value.count_node()?;
                     let token = stringify!($token);
                     let len = token.len();
-                    let start = value.position();
 
                     let mut token_value = value.clone();
+                    token_value.skip_whitespace();
+                    let start = token_value.position();
 
                     let mut mtch = String::new();
                     while mtch.len() < len {
+                        if mtch.len() == 1 {
+                            token_value.set_whitespace(WhitespaceType::KeepAll);
+                        }
+
                         mtch.push(match token_value.next() {
                             Some(value) => value,
                             None => break
@@ -47,7 +117,11 @@ macro_rules! create_tokens {
                         return Ok(Self { span: super::Span::new(start, end)});
                     }
 
-                    Err(ParseError(format!("Could not find token '{}'.", stringify!($token)), token_value.position()))
+                    if mtch.len() < len && token.starts_with(&mtch) {
+                        return Err(ParseError::eof(&format!("Unexpected end of input, expected token '{}'.", stringify!($token)), token_value.position()));
+                    }
+
+                    Err(ParseError::new(&format!("Could not find token '{}'.", stringify!($token)), start))
                 }
 
                 fn span(&self) -> super::Span {
@@ -57,7 +131,7 @@ macro_rules! create_tokens {
 
             impl fmt::Debug for $id {
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
-                    write!(f, "Token({}, at: {})", stringify!($token), self.span.end)
+                    write!(f, "Token({}, at: {})", stringify!($token), self.span.start)
                 }
             }
 
@@ -70,8 +144,33 @@ macro_rules! create_tokens {
     };
 }
 
+/// Parses the text of one side of a delimiter.
+/// Only whitespace before the first character is skipped, so `{ {` does not match `{{`.
+pub(super) fn parse_fence(value: &mut CharStream, fence: &str, side: &str, pair: &str) -> Result<super::Span, ParseError> {
+    value.count_node()?;
+    let mut fence_value = value.clone();
+    fence_value.skip_whitespace();
+    let start = fence_value.position();
+
+    for (index, chr) in fence.chars().enumerate() {
+        if index == 1 {
+            fence_value.set_whitespace(WhitespaceType::KeepAll);
+        }
+
+        match fence_value.next() {
+            Some(next) if next == chr => {}
+            Some(_) => return Err(ParseError::new(&format!("could not find {} side of: '{}'.", side, pair), start)),
+            None => return Err(ParseError::eof(&format!("Unexpected end of input, expected {} side of: '{}'.", side, pair), start))
+        }
+    }
+
+    value.goto(fence_value.position())?;
+    let end = value.position();
+    Ok(super::Span::new(start, end))
+}
+
 macro_rules! create_delimiters {
-    ($($token:tt $left: ident $right: ident $delim:ident),+) => {
+    ($($open:literal $close:literal $left: ident $right: ident $delim:ident),+) => {
         $(
             #[derive(Clone)]
             pub struct $left {
@@ -82,19 +181,8 @@ macro_rules! create_delimiters {
 
             impl Parse for $left {
                 fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-                    let chr = stringify!($token).chars().nth(0).unwrap();
-                    let mut token_value = value.clone();
-                    let start = value.position();
-
-                    if let Some(token) = token_value.next() {
-                        if token == chr {
-                            value.goto(token_value.position())?;
-                            let end = value.position();
-                            return Ok(Self { span: super::Span::new(start, end)})
-                        }
-                    }
-
-                    Err(ParseError(format!("could not find left side of: '{}'.", stringify!($token)), value.position()))
+                    let span = parse_fence(value, $open, "left", concat!($open, $close))?;
+                    Ok(Self { span })
                 }
 
                 fn span(&self) -> super::Span {
@@ -104,13 +192,13 @@ macro_rules! create_delimiters {
 
             impl fmt::Debug for $left {
                 fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-                    write!(f, "Token({})", stringify!($token).chars().nth(0).unwrap())
+                    write!(f, "Token({}, at: {})", $open, self.span.start)
                 }
             }
 
             impl fmt::Display for $left {
                 fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-                    write!(f, "Token({})", stringify!($token).chars().nth(0).unwrap())
+                    write!(f, "Token({})", $open)
                 }
             }
 
@@ -123,19 +211,8 @@ macro_rules! create_delimiters {
 
             impl Parse for $right {
                 fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
-                    let chr = stringify!($token).chars().nth(1).unwrap();
-                    let mut token_value = value.clone();
-                    let start = value.position();
-
-                    if let Some(token) = token_value.next() {
-                        if token == chr {
-                            value.goto(token_value.position())?;
-                            let end = value.position();
-                            return Ok(Self { span: super::Span::new(start, end)})
-                        }
-                    }
-
-                    Err(ParseError(format!("could not find right side of: '{}'.", stringify!($token)), value.position()))
+                    let span = parse_fence(value, $close, "right", concat!($open, $close))?;
+                    Ok(Self { span })
                 }
 
                 fn span(&self) -> super::Span {
@@ -145,13 +222,13 @@ macro_rules! create_delimiters {
 
             impl fmt::Debug for $right {
                 fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-                    write!(f, "Token({})", stringify!($token).chars().nth(1).unwrap())
+                    write!(f, "Token({}, at: {})", $close, self.span.start)
                 }
             }
 
             impl fmt::Display for $right {
                 fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-                    write!(f, "Token({})", stringify!($token).chars().nth(1).unwrap())
+                    write!(f, "Token({})", $close)
                 }
             }
 
@@ -176,32 +253,164 @@ macro_rules! create_delimiters {
                 fn name() -> String {
                     String::from(stringify!($delim))
                 }
+
+                fn open() -> &'static str {
+                    $open
+                }
+
+                fn close() -> &'static str {
+                    $close
+                }
             }
         )+
     };
 }
 
+/// Punct is a token of a single character `C`, like `Punct<'@'>`.
+/// The named single character tokens, like [`Comma`], are aliases of a Punct.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens::{Comma, Punct}, Identifier, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("@user".to_owned()).build();
+/// 	let (at, name) = <(Punct<'@'>, Identifier)>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(at.span().end.column(), 1);
+/// 	assert!(name == "user");
+/// 
+/// 	let mut buffer = CharStream::new("@".to_owned()).build();
+/// 	assert!(Comma::parse(&mut buffer).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Punct<const C: char> {
+    span: super::Span
+}
+
+impl<const C: char> Token for Punct<C> {}
+
+impl<const C: char> Parse for Punct<C> {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        value.count_node()?;
+        let mut token_value = value.clone();
+        token_value.skip_whitespace();
+        let start = token_value.position();
+
+        match token_value.next() {
+            Some(chr) if chr == C => {
+                value.goto(token_value.position())?;
+                let end = value.position();
+                Ok(Self { span: super::Span::new(start, end) })
+            }
+            Some(_) => Err(ParseError::new(&format!("Could not find token '{}'.", C), start)),
+            None => Err(ParseError::eof(&format!("Unexpected end of input, expected token '{}'.", C), start))
+        }
+    }
+
+    fn span(&self) -> super::Span {
+        self.span.clone()
+    }
+}
+
+impl<const C: char> fmt::Debug for Punct<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "Token({}, at: {})", C, self.span.start)
+    }
+}
+
+impl<const C: char> fmt::Display for Punct<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Token({})", C)
+    }
+}
+
+/// Eof is a token without any characters, it only matches at the end of the input.
+/// Whitespace and comments before the end are skipped like for any other token, so the span is empty and sits after them.
+/// 
+/// A tuple that ends with Eof, like `(Number, Eof)`, only parses when nothing significant follows the value.
+/// ```
+/// # use parseal::parsing::{charstream::CharStream, tokens::Eof, Number, Parse};
+/// # fn main() {
+/// 	let mut buffer = CharStream::new("12  \n".to_owned()).build();
+/// 	let (_, eof) = <(Number, Eof)>::parse(&mut buffer).unwrap();
+/// 	assert_eq!(eof.span().start, eof.span().end);
+/// 	assert!(buffer.is_at_end());
+/// 
+/// 	let mut buffer = CharStream::new("12 13".to_owned()).build();
+/// 	let error = <(Number, Eof)>::parse(&mut buffer).unwrap_err();
+/// 	assert_eq!(format!("{:?}", error), "0:3:Error: 'Expected the end of input, found '1'.'");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Eof {
+    span: super::Span
+}
+
+impl Token for Eof {}
+
+impl Parse for Eof {
+    fn parse(value: &mut CharStream) -> Result<Self, ParseError> where Self: Sized {
+        value.count_node()?;
+        let mut token_value = value.clone();
+        token_value.skip_whitespace();
+        let position = token_value.position();
+
+        match token_value.next() {
+            Some(chr) => Err(ParseError::new(&format!("Expected the end of input, found '{}'.", chr), position)),
+            None => {
+                value.goto(position.clone())?;
+                Ok(Self { span: super::Span::new(position.clone(), position) })
+            }
+        }
+    }
+
+    fn span(&self) -> super::Span {
+        self.span.clone()
+    }
+}
+
+impl fmt::Debug for Eof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "Token(EOF, at: {})", self.span.start)
+    }
+}
+
+impl fmt::Display for Eof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Token(EOF)")
+    }
+}
+
+pub type Comma = Punct<','>;
+pub type Period = Punct<'.'>;
+pub type Bang = Punct<'!'>;
+pub type Hash = Punct<'#'>;
+pub type UnderScore = Punct<'_'>;
+pub type Hyphen = Punct<'-'>;
+pub type Plus = Punct<'+'>;
+pub type Equal = Punct<'='>;
+pub type Colon = Punct<':'>;
+pub type Semicolon = Punct<';'>;
+pub type Pipe = Punct<'|'>;
+pub type Ampersand = Punct<'&'>;
+pub type Less = Punct<'<'>;
+pub type Greater = Punct<'>'>;
+pub type ForwardSlash = Punct<'/'>;
+
 create_tokens! {
-    , Comma,
-    . Period,
-    ! Bang,
-    # Hash,
-    _ UnderScore,
-    - Hyphen,
-    + Plus,
-    = Equal,
     == EqualEqual,
-    : Colon,
-    ; Semicolon,
-    | Pipe,
-    < Less,
-    > Greater,
-    / ForwardSlash
+    :: DoubleColon,
+    && AndAnd,
+    || OrOr,
+    .. DotDot,
+    ..= DotDotEq,
+    >= GreaterEqual,
+    <= LessEqual
 }
 
 create_delimiters! {
-    () LeftParen RightParen Paren,
-    {} LeftBrace RightBrace Brace,
-    [] LeftBracket RightBracket Bracket,
-    "" LeftQuote RightQuote Quote
+    "(" ")" LeftParen RightParen Paren,
+    "{" "}" LeftBrace RightBrace Brace,
+    "[" "]" LeftBracket RightBracket Bracket,
+    "\"" "\"" LeftQuote RightQuote Quote,
+    "{{" "}}" LeftDoubleBrace RightDoubleBrace DoubleBrace,
+    "<%" "%>" LeftTemplateTag RightTemplateTag TemplateTag
 }
@@ -0,0 +1,31 @@
+//! The prelude re-exports the most used traits and types, so they can be imported at once:
+//! ```
+//! use parseal::prelude::*;
+//! 
+//! # fn main() {
+//! 	let mut buffer = CharStream::new("[1, 2, 3]".to_owned()).build();
+//! 	let value = Group::<Bracket, List<Number, Comma>>::parse(&mut buffer).unwrap();
+//! 	assert_eq!(value.item().items().count(), 3);
+//! 
+//! 	#[cfg(feature="derive")]
+//! 	{
+//! 		#[derive(Parsable, Clone)]
+//! 		struct Assign(Identifier, Equal, StringValue);
+//! 
+//! 		let mut buffer = CharStream::new("name = \"parseal\"".to_owned()).build();
+//! 		assert!(Assign::parse(&mut buffer).is_ok());
+//! 	}
+//! # }
+//! ```
+//! The `parsing` module itself is included as well, because the code generated by the derive macros refers to it.
+//! No names from the standard prelude are shadowed.
+
+pub use crate::parsing::{
+    self,
+    charstream::{CharStream, Span},
+    tokens::{self, Brace, Bracket, Colon, Comma, Equal, Paren, Semicolon, Token},
+    Group, Identifier, List, Number, Pair, Parse, ParseError, StringValue
+};
+
+#[cfg(feature = "derive")]
+pub use crate::{Parsable, ParsableKeyword};